@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use adler2::Adler32 as Adler32State;
+use digest::consts::U4;
+
+checksum_digest!(
+    Adler32Digest,
+    Adler32State,
+    U4,
+    Adler32State::new(),
+    Adler32State::write_slice,
+    Adler32State::checksum
+);
+
+/// Adler-32, the checksum zlib streams end with.  It isn't cryptographic and is weak for short content.
+pub struct Adler32 {}
+
+impl Adler32 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 32,
+            name: "Adler-32",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Adler32Digest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Adler32;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Adler32, empty, U8_EMPTY, "00000001");
+    test_algorithm!(Adler32, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "00200001");
+    test_algorithm!(Adler32, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "1f1001e1");
+    test_algorithm!(Adler32, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "0e2e1fe1");
+    test_algorithm!(Adler32, one_byte, U8_1_BYTE, "00660066");
+    test_algorithm!(Adler32, two_bytes, U8_2_BYTES, "017d00d0");
+    test_algorithm!(Adler32, three_bytes, U8_3_BYTES, "037f01d2");
+    test_algorithm!(Adler32, four_bytes, U8_4_BYTES, "08860356");
+    test_algorithm!(Adler32, ascending, U8_32_ASCENDING, "17800211");
+    test_algorithm!(Adler32, descending, U8_32_DESCENDING, "2cd00211");
+    test_algorithm_s!(Adler32, hi, S_HI, "013b00d2");
+    test_algorithm_s!(Adler32, quick_brown_fox, S_QUICK_BROWN_FOX, "65ca0f9e");
+    test_algorithm_s!(Adler32, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "75720fa8");
+    test_algorithm_s!(Adler32, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "46e10747");
+    test_algorithm!(Adler32, six_thousand_bytes_of_all_ones, U8_6000_ALL_ONES, "a49759ea");
+    test_algorithm_s!(Adler32, check, "123456789", "091e01de");
+}
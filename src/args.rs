@@ -1,32 +1,222 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT-0
 
-use clap::Parser;
+use crate::walk::WalkOptions;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use digestify::{Error, Registry, Strength};
+use glob::Pattern;
+use std::io::ErrorKind;
+use std::num::NonZeroUsize;
 use std::path::Path;
 
-pub fn valid_hex_string(hash: &str) -> Result<String, String> {
-    match hex::decode(hash) {
-        Ok(_) => Ok(hash.to_string()),
-        Err(_) => Err(String::from(
-            "The provided has does not seem to be a valid hexadecimal string.",
-        )),
-    }
+/// Fails if any of `names` isn't a registered algorithm.  This and the other values checked here are validated after
+/// parsing rather than by clap, which would report every invalid value as a usage error, so that each kind of error
+/// exits with its own code.
+pub fn known_algorithms(registry: &Registry, names: &[String]) -> Result<(), Error> {
+    names.iter().try_for_each(|name| registry.lookup(name).map(|_| ()))
+}
+
+pub fn known_strength(name: &str) -> Result<Strength, Error> {
+    Strength::from_name(name).ok_or_else(|| {
+        let names: Vec<&str> = Strength::ALL.iter().map(Strength::name).collect();
+        Error::InvalidArgument(format!(
+            "Unknown strength '{}'.  Expected one of {}.",
+            name,
+            names.join(", ")
+        ))
+    })
 }
 
-pub fn readable_files_name(file_name: &str) -> Result<String, String> {
+/// The file name that stands for standard input.
+pub const STDIN_FILE_NAME: &str = "-";
+
+pub fn readable_files_name(file_name: &str) -> Result<String, Error> {
     if Path::new(&file_name).exists() {
         return Ok(file_name.to_string());
     }
-    Err(String::from(format!("File '{}' cannot be found", file_name)))
+    Err(std::io::Error::new(ErrorKind::NotFound, format!("File '{}' cannot be found", file_name)).into())
+}
+
+pub fn valid_pattern(pattern: &str) -> Result<Pattern, Error> {
+    Pattern::new(pattern).map_err(|e| Error::InvalidArgument(format!("Invalid pattern '{}': {}", pattern, e)))
 }
 
 #[derive(Parser)]
-#[clap(version, about)]
+#[clap(
+    version,
+    about,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 pub struct DigestifyArgs {
-    /// File to verify
-    #[clap(name="file", value_parser=readable_files_name)]
-    pub file_name: String,
+    /// File to verify, or `-` for standard input.  If only a digest is given, standard input is verified.
+    #[clap(name="file", required_unless_present="check")]
+    pub file_name: Option<String>,
+
+    /// Digest to compare to, specified as a case-insensitive hexadecimal string, a base64, base32 or Nix base32 string,
+    /// a BSD style `ALGO (file) = hex` line or a Subresource Integrity string like `sha384-<base64>`.
+    #[clap(name="digest")]
+    pub digest: Option<String>,
+
+    /// Verify every file listed in a sha256sum/md5sum style checksum file instead of a single file and digest
+    #[clap(short, long, name="check", value_name="SUMSFILE", conflicts_with_all=&["file", "digest"])]
+    pub check: Option<String>,
+
+    /// Files or directories to verify against the checksum file, given after `--`.  Only listed files at or under them
+    /// are verified, and any others found there are reported as extra.
+    #[clap(name="paths", last=true, requires="check")]
+    pub paths: Vec<String>,
+
+    /// Verify the files in directories given after `--`, and in their subdirectories
+    #[clap(short, long, requires="check")]
+    pub recursive: bool,
+
+    /// Only verify files whose path matches this glob pattern.  May be repeated.
+    #[clap(long, value_name="GLOB", requires="check")]
+    pub include: Vec<String>,
+
+    /// Don't verify files whose path matches this glob pattern.  May be repeated.
+    #[clap(long, value_name="GLOB", requires="check")]
+    pub exclude: Vec<String>,
+
+    /// Include hidden files and directories, whose names start with a `.`, when walking directories
+    #[clap(long, requires="check")]
+    pub hidden: bool,
+
+    /// How symbolic links found when walking directories are treated
+    #[clap(long, value_enum, default_value_t=SymlinkPolicy::Files, requires="check")]
+    pub symlinks: SymlinkPolicy,
+
+    /// Number of files to hash at once
+    #[clap(short, long, value_name="N", default_value="1", requires="check")]
+    pub jobs: NonZeroUsize,
+
+    /// Algorithm the digest was computed with, e.g. SHA-256, instead of inferring candidates from the digest's length.
+    /// May be repeated to try more than one.
+    #[clap(short, long="algorithm", name="algorithm")]
+    pub algorithms: Vec<String>,
+
+    #[clap(flatten)]
+    pub key: KeyArgs,
+
+    /// Fail with exit code 9 if the content only matches digests weaker than this: `non-cryptographic`, `broken`,
+    /// `legacy` or `strong`.
+    #[clap(long, value_name="STRENGTH", conflicts_with="deny-weak")]
+    pub min_strength: Option<String>,
+
+    /// Fail with exit code 9 if the content only matches weak digests, i.e. non-cryptographic checksums, broken hashes
+    /// like MD5 and SHA-1 or legacy hashes.  The same as `--min-strength strong`.
+    #[clap(long)]
+    pub deny_weak: bool,
+
+    /// How results are written.  `json` writes one JSON object per file, followed by a summary when checking a
+    /// checksum file.
+    #[clap(long, value_enum, default_value_t=OutputFormat::Text)]
+    pub format: OutputFormat,
+
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl DigestifyArgs {
+    pub fn walk_options(&self) -> Result<WalkOptions, Error> {
+        Ok(WalkOptions {
+            recursive: self.recursive,
+            include: self.include.iter().map(|p| valid_pattern(p)).collect::<Result<_, _>>()?,
+            exclude: self.exclude.iter().map(|p| valid_pattern(p)).collect::<Result<_, _>>()?,
+            hidden: self.hidden,
+            symlinks: self.symlinks,
+        })
+    }
+
+    /// The weakest digest a match is accepted with.  By default any match is accepted.
+    pub fn min_strength(&self) -> Result<Strength, Error> {
+        match (self.deny_weak, &self.min_strength) {
+            (true, _) => Ok(Strength::Strong),
+            (false, Some(name)) => known_strength(name),
+            (false, None) => Ok(Strength::NonCryptographic),
+        }
+    }
+
+    /// Returns the file and digest to verify.  A single positional argument is the digest of standard input.  These are
+    /// validated here rather than by clap since which argument is which isn't known until both have been parsed.
+    pub fn file_and_digest(&self) -> Result<(String, String), clap::Error> {
+        match (&self.file_name, &self.digest) {
+            (Some(file_name), Some(digest)) => Ok((file_name.clone(), digest.clone())),
+            (Some(digest), None) => Ok((STDIN_FILE_NAME.to_string(), digest.clone())),
+            _ => Err(Self::command().error(clap::ErrorKind::MissingRequiredArgument, "A digest is required")),
+        }
+    }
+}
+
+/// The key for keyed digests, which both verifying and generating take.
+#[derive(Args)]
+pub struct KeyArgs {
+    /// Use keyed digests, such as HMAC-SHA256 tags, with the key read from this file.  The whole file is the key, so it
+    /// shouldn't end with a newline.  Block hashes are keyed as HMACs, and BLAKE2 and BLAKE3 use their keyed modes.
+    #[clap(long, value_name="FILE", conflicts_with="hmac-key-env")]
+    pub hmac_key_file: Option<String>,
+
+    /// Like --hmac-key-file, but the key is the value of this environment variable
+    #[clap(long, value_name="VAR")]
+    pub hmac_key_env: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Compute digests of files and write them out as a checksum manifest
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Files, or glob patterns matching files, to compute digests of
+    #[clap(name="files", required=true)]
+    pub files: Vec<String>,
+
+    /// Algorithm to compute, e.g. SHA-256.  May be repeated to compute more than one.
+    #[clap(short, long="algorithm", name="algorithm", default_value="SHA-256")]
+    pub algorithms: Vec<String>,
+
+    /// Format of the manifest
+    #[clap(short, long, value_enum, default_value_t=ManifestFormat::Gnu)]
+    pub format: ManifestFormat,
+
+    /// Write the manifest to this file instead of standard output
+    #[clap(short, long, value_name="FILE")]
+    pub output: Option<String>,
+
+    /// Number of files to hash at once
+    #[clap(short, long, value_name="N", default_value="1")]
+    pub jobs: NonZeroUsize,
+
+    #[clap(flatten)]
+    pub key: KeyArgs,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+pub enum ManifestFormat {
+    /// `<hex>  <name>` lines as written by sha256sum, md5sum, etc.
+    Gnu,
+    /// `ALGO (<name>) = <hex>` lines as written by `shasum --tag`, BSD `sha256`, etc.
+    Bsd,
+    /// A JSON document listing each file's size and digests
+    Json,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable PASS/FAIL lines
+    Text,
+    /// Newline-delimited JSON records
+    Json,
+}
 
-    /// Digest to compare to, specified as a case-insensitive hexadecimal string.
-    #[clap(name="digest", value_parser=valid_hex_string)]
-    pub digest: String,
+#[derive(Copy, Clone, ValueEnum)]
+pub enum SymlinkPolicy {
+    /// Skip symbolic links
+    Skip,
+    /// Verify symbolic links to files, but don't walk symbolic links to directories
+    Files,
+    /// Verify symbolic links to files and walk symbolic links to directories
+    Follow,
 }
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{Algorithm, CalculatedDigest, Error};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+impl<'a> Algorithm<'a> {
+    /// Like `digest`, but reads the content from an `AsyncRead`, e.g. a `tokio::fs::File` or a socket.
+    pub async fn digest_async(&self, read: &mut (impl AsyncRead + Unpin)) -> Result<CalculatedDigest, Error> {
+        let mut calculated = digest_all_async(std::slice::from_ref(self), read).await?;
+        Ok(calculated.remove(0))
+    }
+}
+
+/// Like `digest_all`, but reads the content from an `AsyncRead`.  The content is still only read once no matter how
+/// many algorithms are requested, and the results are returned in the same order as `algorithms`.
+pub async fn digest_all_async(
+    algorithms: &[Algorithm<'_>],
+    read: &mut (impl AsyncRead + Unpin),
+) -> Result<Vec<CalculatedDigest>, Error> {
+    let mut hashers: Vec<_> = algorithms.iter().map(|a| a.hasher()).collect();
+    let mut buffer = vec![0; BUFFER_SIZE];
+    loop {
+        let read_size = match read.read(&mut buffer).await {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        for hasher in hashers.iter_mut() {
+            hasher.update(&buffer[..read_size]);
+        }
+    }
+    Ok(hashers.into_iter().map(|h| h.finalize()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::digest_all_async;
+    use crate::tests::{block_on, S_QUICK_BROWN_FOX};
+    use crate::{crc32::Crc32, digest_all, md5::Md5, sha256::Sha256, shake256::Shake256};
+
+    #[test]
+    fn digest_all_async_matches_digest_all() {
+        let algorithms = [Crc32::new(), Md5::new(), Sha256::new(), Shake256::new()];
+        let expected = digest_all(&algorithms, &mut S_QUICK_BROWN_FOX.as_bytes()).unwrap();
+        let calculated = block_on(digest_all_async(&algorithms, &mut S_QUICK_BROWN_FOX.as_bytes())).unwrap();
+        assert_eq!(calculated.len(), algorithms.len());
+        for (e, c) in expected.iter().zip(calculated) {
+            assert_eq!(c.algorithm_name, e.algorithm_name);
+            assert_eq!(c.bytes_read, e.bytes_read);
+            assert_eq!(c.digest, e.digest);
+        }
+    }
+}
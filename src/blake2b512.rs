@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{new_hasher, new_mac_hasher, Algorithm, Keying, Strength};
+
+pub struct Blake2b512 {}
+
+impl Blake2b512 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 512,
+            name: "BLAKE2b-512",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<blake2::Blake2b512>,
+            keying: Some(Keying {
+                name: "Keyed-BLAKE2b-512",
+                hasher_fn: new_mac_hasher::<blake2::Blake2bMac512>,
+                key: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Blake2b512;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Blake2b512, empty, U8_EMPTY, "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce");
+    test_algorithm!(Blake2b512, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "9ab7a73a97a1a3031406b6c169634a9c06cfb81dec3323bb4de5ce6f4b7ca107de534442a7eaeafbaf366ccfdde1cb97d7c884e4344cd0a23039de71a56d630a");
+    test_algorithm!(Blake2b512, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "6939a3d9dbe4d03a15baa26f6c93c393a39fa5f7f1625ccaffb178ca697570c7305317824f2e165bb9e5eedae13107c7a24f05dd15a97439a1a99a16c5332d34");
+    test_algorithm!(Blake2b512, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "83b5ade6991342ed779f9bf19d6da4321bf795d80ab0b01f6f804fa5f7d9df2159f76d8f3719f7cd08ace7db6ec97fd73662ca346ac48caf394cfd35a1aa9ff9");
+    test_algorithm!(Blake2b512, one_byte, U8_1_BYTE, "faaa8a2965e6e1c5448eb4e6e647683333635103abcbf41ab013f8cf5e33df43ef2f9574042959f86f95ecca8cef9ca7d631ff3bd0bb213fa2a6769a319cfb4d");
+    test_algorithm!(Blake2b512, two_bytes, U8_2_BYTES, "6ccbd8166591a1e2675804ca433421b9cabed76d6003ce63fc165860ab3812f2f5361c44e5add17ce650eac2006836d8b1325955511b7ed15a13e976b95ab4a1");
+    test_algorithm!(Blake2b512, three_bytes, U8_3_BYTES, "e33e76d1d37f85c4df705c0971a592e8d32e7088e6835e8d1fe888d770b4a59f08c047883b62a17876e0cf1fbafd1a8e1e12825885626fa79e1e42dbd56853e1");
+    test_algorithm!(Blake2b512, four_bytes, U8_4_BYTES, "b3062227781d49fe7b9b09dc7bcb2c547d356a08787e59034435672437da14bb1dfb3d30f128eb3c8edca266812da4c18a514f645e2682de27ed80fe610f0f17");
+    test_algorithm!(Blake2b512, ascending, U8_32_ASCENDING, "45ba7364fa0071c91802cff284861e52b656972d77002f29e4807fdb21b909134ac8ac110c6d15c5a1c6e0950fa2fbb29933fced1b924d1b1fe7aeee29d426cb");
+    test_algorithm!(Blake2b512, descending, U8_32_DESCENDING, "9853ff92959c7755c7f7575ed4e732474b55c8471d05cced4cc56e52639d2917abd52ba38742a2eb0be7b8b68ab702fe0c4c853c975f156dc895e80c9422e810");
+    test_algorithm_s!(Blake2b512, hi, S_HI, "bfbcbe7ade93034ee0a41a2ea7b5fd81d89bdb1d75d1af230ea37d7abe71078f1df6db4d251cbc6b58e8963db2546f0f539c80b0f08c0fdd8c0a71075c97b3e7");
+    test_algorithm_s!(Blake2b512, quick_brown_fox, S_QUICK_BROWN_FOX, "ed765b067e034abcfa865680e7782df980a8004457f348a19a26c745faa452992853abf19d731ea0db45c63fa768b4f9b602d5a18c823868f175eb6699df2454");
+    test_algorithm_s!(Blake2b512, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "06e86bc43cbfd03ba099bb14345b5b35bf1d56233697aed7d796db15692a6cba70ab9fff374bad81816cc040a874e004619ecd37efd41816d2f0fb5682b27d9f");
+    test_algorithm_s!(Blake2b512, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "43f3f211c61702797df7cfe8545b8ec9c28a1852b437a473b26e3d4fda90a9894adfefde3cdbf0e303d9d2de8504d061bcd088b694653df2e55204ac67b54b9c");
+    test_keyed_algorithm_s!(Blake2b512, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "fa7c0fbedeb446cf43b858c958f8c71213b3dfde4041b2b5db84a154f58a638c6b06e779869d201c22f27bb7b66ec7b661b9b5d71bfc075fd2777d3069af54e5");
+}
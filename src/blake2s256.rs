@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{new_hasher, new_mac_hasher, Algorithm, Keying, Strength};
+
+pub struct Blake2s256 {}
+
+impl Blake2s256 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 256,
+            name: "BLAKE2s-256",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<blake2::Blake2s256>,
+            keying: Some(Keying {
+                name: "Keyed-BLAKE2s-256",
+                hasher_fn: new_mac_hasher::<blake2::Blake2sMac256>,
+                key: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Blake2s256;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Blake2s256, empty, U8_EMPTY, "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9");
+    test_algorithm!(Blake2s256, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "320b5ea99e653bc2b593db4130d10a4efd3a0b4cc2e1a6672b678d71dfbd33ad");
+    test_algorithm!(Blake2s256, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "358cb619851dafae0b8b16ceafb9fd4998c7235a954121ca2d4142369cb8bc57");
+    test_algorithm!(Blake2s256, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "61d3efa051909b2a85c53114ce79cc023cd3adbfd8917aead7dd3086c83617ad");
+    test_algorithm!(Blake2s256, one_byte, U8_1_BYTE, "82c10b274c7343a363fc9235140a096a1791c97960b6993f4ad69c8772b94691");
+    test_algorithm!(Blake2s256, two_bytes, U8_2_BYTES, "392041df14b3589171c286d9b03c3eaac750027b7cb343d279c066dd765ec246");
+    test_algorithm!(Blake2s256, three_bytes, U8_3_BYTES, "c41aed1ac73d1908198307af8bba9f5d42eb65fe5460f6b539e4605a1b405d25");
+    test_algorithm!(Blake2s256, four_bytes, U8_4_BYTES, "ac82823720a0ffba4a37dce5f600a1f38c33698b674a803d3234bead622ee4b8");
+    test_algorithm!(Blake2s256, ascending, U8_32_ASCENDING, "729a830fdca546437521d4a6145a173845d857dc91ae088cc8c480c38897e437");
+    test_algorithm!(Blake2s256, descending, U8_32_DESCENDING, "1a377e5671503ddbd867c3afeb992ca82a02f9d495bcc7c81e46248e22c70eef");
+    test_algorithm_s!(Blake2s256, hi, S_HI, "f509150e97310e8e59d0a328a6cf9042d104c94c3bbf21c8ed9e6957ef1e5a64");
+    test_algorithm_s!(Blake2s256, quick_brown_fox, S_QUICK_BROWN_FOX, "358ac38feb33fd17b676c5985265b3387b01d3438b737b2c7799424eeb84cbd8");
+    test_algorithm_s!(Blake2s256, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "b4b7709fc7cc340f46fce6f055e367d5d4417ba17fc9ff1682557860a778f400");
+    test_algorithm_s!(Blake2s256, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "17bfbdadd1b0b65a17c2d0a9499d110b5fd7c1e1ba1fa863885d8c82c60c3172");
+    test_keyed_algorithm_s!(Blake2s256, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "83e2f4bebf4d8f1a7b67a7e3b9e6fb870d52fa342474bc9243abf8090e0f3e87");
+}
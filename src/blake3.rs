@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{new_hasher, Algorithm, Keying, Strength};
+use digest::{consts::U32, DynDigest, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+/// Input is collected into buffers of this size so it can be hashed using multiple threads.
+const MULTI_THREADED_BUFFER_SIZE: usize = 1024 * 1024;
+
+#[derive(Clone, Default)]
+struct Blake3Digest {
+    hasher: blake3::Hasher,
+    buffer: Vec<u8>,
+}
+
+impl Blake3Digest {
+    fn flush(&mut self) {
+        self.hasher.update_rayon(&self.buffer);
+        self.buffer.clear();
+    }
+}
+
+impl HashMarker for Blake3Digest {}
+
+impl OutputSizeUser for Blake3Digest {
+    type OutputSize = U32;
+}
+
+impl FixedOutput for Blake3Digest {
+    fn finalize_into(mut self, out: &mut Output<Self>) {
+        self.flush();
+        out.clone_from_slice(self.hasher.finalize().as_bytes());
+    }
+}
+
+impl FixedOutputReset for Blake3Digest {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.flush();
+        out.clone_from_slice(self.hasher.finalize().as_bytes());
+        Reset::reset(self);
+    }
+}
+
+impl Reset for Blake3Digest {
+    fn reset(&mut self) {
+        self.hasher.reset();
+        self.buffer.clear();
+    }
+}
+
+impl Update for Blake3Digest {
+    fn update(&mut self, input: &[u8]) {
+        if self.buffer.is_empty() && input.len() >= MULTI_THREADED_BUFFER_SIZE {
+            self.hasher.update_rayon(input);
+            return;
+        }
+        self.buffer.extend_from_slice(input);
+        if self.buffer.len() >= MULTI_THREADED_BUFFER_SIZE {
+            self.flush();
+        }
+    }
+}
+
+/// BLAKE3's keyed mode takes exactly 32 bytes of key.
+fn new_keyed_hasher(key: &[u8]) -> Option<Box<dyn DynDigest>> {
+    Some(Box::new(Blake3Digest {
+        hasher: blake3::Hasher::new_keyed(key.try_into().ok()?),
+        buffer: Vec::new(),
+    }))
+}
+
+/// Uses SIMD and, for content larger than a megabyte, multiple threads.
+pub struct Blake3 {}
+
+impl Blake3 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 256,
+            name: "BLAKE3",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<Blake3Digest>,
+            keying: Some(Keying {
+                name: "Keyed-BLAKE3",
+                hasher_fn: new_keyed_hasher,
+                key: None,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Blake3;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Blake3, empty, U8_EMPTY, "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262");
+    test_algorithm!(Blake3, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "2ada83c1819a5372dae1238fc1ded123c8104fdaa15862aaee69428a1820fcda");
+    test_algorithm!(Blake3, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "af3a03beb61762079e2f795a1a0b06bebe1f5618c4046f5ffc72c3a1fcc7f2ce");
+    test_algorithm!(Blake3, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "9b34f060fbc0f0aa11f150e26519deff613277b60656f0f8356ed2261505f5c5");
+    test_algorithm!(Blake3, one_byte, U8_1_BYTE, "27bb492e108bf5e9c724176d7ae75d4cedc422fe4065020bd6140c3fcad3a9e7");
+    test_algorithm!(Blake3, two_bytes, U8_2_BYTES, "b1ede3274aff3a3deed58d20b7c1127b28d4dcbe9e16659c83a2a93d79f788f0");
+    test_algorithm!(Blake3, three_bytes, U8_3_BYTES, "6689b3b488b82082500bf593bef09128aefbdcdee2ebcb3437a40b57bb061d71");
+    test_algorithm!(Blake3, four_bytes, U8_4_BYTES, "2d9cc61d0413d7f91b458c1380e1eb6301e559444520109cb24607f14e4eb732");
+    test_algorithm!(Blake3, ascending, U8_32_ASCENDING, "f0d184663caeb47e78a42f7235250fa0d61962a149624d05729b1e9537c6454f");
+    test_algorithm!(Blake3, descending, U8_32_DESCENDING, "5007e44df906cf2d9d9ae959d1341d236d307efb9a7bca4cdcb2b17d2271aa8e");
+    test_algorithm_s!(Blake3, hi, S_HI, "85052e9aab1b67b6622d94a08441b09fd5b7aca61ee360416d70de5da67d86ca");
+    test_algorithm_s!(Blake3, quick_brown_fox, S_QUICK_BROWN_FOX, "e27dd966155f987bd38bd3db8266df4fef45b4077e9661a9811c8c994fd892ac");
+    test_algorithm_s!(Blake3, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "647aa0b0e0bb9fe26d82b70a80901d7c9907fe5bab90d47bbc8b5a935c40cc50");
+    test_algorithm_s!(Blake3, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "c18d42de3786bdf9813f8b8bbd95c3f4dda4ffc29ead21d5ac54deec5702011f");
+
+    #[test]
+    fn larger_than_multi_threaded_buffer() {
+        let data: Vec<u8> = (0..3 * 1024 * 1024 + 17).map(|i| (i % 251) as u8).collect();
+        crate::tests::test_algorithm(
+            &Blake3::new(),
+            &mut std::io::Cursor::new(&data),
+            data.len() as u64,
+            "26003c63117013de5d02be76e5e32a2f75bfbc075f17180fd5f9f0b4752d2bfe",
+        );
+    }
+    test_keyed_algorithm_s!(Blake3, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "4af70385b998b962eefc87d7354e64016f0f303a39c7fb2700e84f35eeb0b61d");
+}
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+/// Defines `$digest`, an adapter from a non-cryptographic checksum to the `digest` traits.  `$new` creates the
+/// checksum's `$state`, `$update` adds input to it and `$value` returns the checksum as an integer.  That's output as
+/// big-endian bytes, so the hex digest is the checksum as it's usually written, e.g. by `xxhsum` and zlib.  A `crc`
+/// crate CRC is given as `crc::Crc<$width>` and its `$algorithm`.
+macro_rules! checksum_digest {
+    ($digest:ident, crc::Crc<$width:ty>, $output_size:ty, $algorithm:expr) => {
+        static CRC: crc::Crc<$width> = crc::Crc::<$width>::new($algorithm);
+
+        checksum_digest!(
+            $digest,
+            crc::Digest<'static, $width>,
+            $output_size,
+            CRC.digest(),
+            crc::Digest::<'static, $width>::update,
+            |state: &crc::Digest<'static, $width>| state.clone().finalize()
+        );
+    };
+    ($digest:ident, $state:ty, $output_size:ty, $new:expr, $update:path, $value:expr) => {
+        #[derive(Clone)]
+        struct $digest {
+            state: $state,
+        }
+
+        impl Default for $digest {
+            fn default() -> Self {
+                Self { state: $new }
+            }
+        }
+
+        impl digest::HashMarker for $digest {}
+
+        impl digest::OutputSizeUser for $digest {
+            type OutputSize = $output_size;
+        }
+
+        impl digest::FixedOutput for $digest {
+            fn finalize_into(self, out: &mut digest::Output<Self>) {
+                out.clone_from_slice(&($value)(&self.state).to_be_bytes());
+            }
+        }
+
+        impl digest::FixedOutputReset for $digest {
+            fn finalize_into_reset(&mut self, out: &mut digest::Output<Self>) {
+                out.clone_from_slice(&($value)(&self.state).to_be_bytes());
+                digest::Reset::reset(self);
+            }
+        }
+
+        impl digest::Reset for $digest {
+            fn reset(&mut self) {
+                self.state = $new;
+            }
+        }
+
+        impl digest::Update for $digest {
+            fn update(&mut self, input: &[u8]) {
+                $update(&mut self.state, input);
+            }
+        }
+    };
+}
+
+pub(crate) use checksum_digest;
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{new_hasher, Algorithm, Strength};
+use digest::{consts::U4, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+static CRC: crc::Crc<u32> = crc::Crc::<u32>::new(&crc::CRC_32_CKSUM);
+
+/// Like `Crc32Digest`, but for the CRC written by POSIX `cksum`, which folds the content's length into the CRC.
+#[derive(Clone)]
+struct CksumDigest {
+    hasher: crc::Digest<'static, u32>,
+    length: u64,
+}
+
+impl CksumDigest {
+    /// Appends the length, least significant byte first and using as few bytes as possible, then finalizes the CRC.
+    fn finalize_crc(mut self) -> u32 {
+        let length = self.length.to_le_bytes();
+        let significant = length.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        self.hasher.update(&length[..significant]);
+        self.hasher.finalize()
+    }
+}
+
+impl Default for CksumDigest {
+    fn default() -> Self {
+        Self {
+            hasher: CRC.digest(),
+            length: 0,
+        }
+    }
+}
+
+impl HashMarker for CksumDigest {}
+
+impl OutputSizeUser for CksumDigest {
+    type OutputSize = U4;
+}
+
+impl FixedOutput for CksumDigest {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.clone_from_slice(&self.finalize_crc().to_be_bytes());
+    }
+}
+
+impl FixedOutputReset for CksumDigest {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.clone_from_slice(&self.clone().finalize_crc().to_be_bytes());
+        Reset::reset(self);
+    }
+}
+
+impl Reset for CksumDigest {
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl Update for CksumDigest {
+    fn update(&mut self, input: &[u8]) {
+        self.hasher.update(input);
+        self.length += input.len() as u64;
+    }
+}
+
+/// The CRC written by POSIX `cksum`.  `cksum` prints it in decimal, but like the other CRCs its digest is big-endian
+/// hex.
+pub struct Cksum {}
+
+impl Cksum {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 32,
+            name: "cksum",
+            extendable_output: false,
+            crc: true,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<CksumDigest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cksum;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Cksum, empty, U8_EMPTY, "ffffffff");
+    test_algorithm!(Cksum, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "67dc491f");
+    test_algorithm!(Cksum, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "1dd81576");
+    test_algorithm!(Cksum, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "a3da83e3");
+    test_algorithm!(Cksum, one_byte, U8_1_BYTE, "0de65857");
+    test_algorithm!(Cksum, two_bytes, U8_2_BYTES, "7ad1a820");
+    test_algorithm!(Cksum, three_bytes, U8_3_BYTES, "abd5ea82");
+    test_algorithm!(Cksum, four_bytes, U8_4_BYTES, "c4225b08");
+    test_algorithm!(Cksum, ascending, U8_32_ASCENDING, "272016ad");
+    test_algorithm!(Cksum, descending, U8_32_DESCENDING, "923b01c9");
+    test_algorithm_s!(Cksum, hi, S_HI, "8c32c96d");
+    test_algorithm_s!(Cksum, quick_brown_fox, S_QUICK_BROWN_FOX, "25c0848a");
+    test_algorithm_s!(Cksum, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "920eae73");
+    test_algorithm_s!(Cksum, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "87f53c7f");
+    test_algorithm_s!(Cksum, check, "123456789", "377a6011");
+}
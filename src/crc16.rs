@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U2;
+
+checksum_digest!(Crc16Digest, crc::Crc<u16>, U2, &crc::CRC_16_ARC);
+
+/// CRC-16/ARC, also known as CRC-16/IBM, used by ARC and LHA archives and usually what's meant by plain CRC-16.
+pub struct Crc16 {}
+
+impl Crc16 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 16,
+            name: "CRC-16",
+            extendable_output: false,
+            crc: true,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Crc16Digest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Crc16;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Crc16, empty, U8_EMPTY, "0000");
+    test_algorithm!(Crc16, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "0000");
+    test_algorithm!(Crc16, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "8aef");
+    test_algorithm!(Crc16, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "a401");
+    test_algorithm!(Crc16, one_byte, U8_1_BYTE, "2bc0");
+    test_algorithm!(Crc16, two_bytes, U8_2_BYTES, "d93c");
+    test_algorithm!(Crc16, three_bytes, U8_3_BYTES, "5d55");
+    test_algorithm!(Crc16, four_bytes, U8_4_BYTES, "43fe");
+    test_algorithm!(Crc16, ascending, U8_32_ASCENDING, "f742");
+    test_algorithm!(Crc16, descending, U8_32_DESCENDING, "7d51");
+    test_algorithm_s!(Crc16, hi, S_HI, "eeef");
+    test_algorithm_s!(Crc16, quick_brown_fox, S_QUICK_BROWN_FOX, "b337");
+    test_algorithm_s!(Crc16, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "d172");
+    test_algorithm_s!(Crc16, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "afe0");
+    test_algorithm_s!(Crc16, check, "123456789", "bb3d");
+}
@@ -1,9 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT-0
 
-use crate::calculated_digest;
-use digest::{consts::U4, FixedOutput, HashMarker, Output, OutputSizeUser, Update};
-use std::io::Write;
+use crate::new_hasher;
+use digest::{consts::U4, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
 
+#[derive(Clone)]
 struct Crc32Digest {
     hasher: crc32fast::Hasher,
 }
@@ -28,20 +28,23 @@ impl FixedOutput for Crc32Digest {
     }
 }
 
-impl Update for Crc32Digest {
-    fn update(&mut self, input: &[u8]) {
-        self.hasher.update(input);
+impl FixedOutputReset for Crc32Digest {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        let beb = self.hasher.clone().finalize().to_be_bytes();
+        out.clone_from_slice(&beb);
+        Reset::reset(self);
     }
 }
 
-impl Write for Crc32Digest {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        digest::Update::update(self, buf);
-        Ok(buf.len())
+impl Reset for Crc32Digest {
+    fn reset(&mut self) {
+        self.hasher.reset();
     }
+}
 
-    fn flush(&mut self) -> std::io::Result<()> {
-        Ok(())
+impl Update for Crc32Digest {
+    fn update(&mut self, input: &[u8]) {
+        self.hasher.update(input);
     }
 }
 
@@ -52,7 +55,11 @@ impl Crc32 {
         super::Algorithm {
             digest_bit_size: 32,
             name: "CRC-32",
-            digest_fn: calculated_digest::<Crc32Digest>,
+            extendable_output: false,
+            crc: true,
+            strength: super::Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Crc32Digest>,
+            keying: None,
         }
     }
 }
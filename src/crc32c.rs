@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U4;
+
+checksum_digest!(Crc32cDigest, crc::Crc<u32>, U4, &crc::CRC_32_ISCSI);
+
+/// CRC-32C (Castagnoli), used by iSCSI, ext4, Google Cloud Storage and Amazon S3 checksums.
+pub struct Crc32c {}
+
+impl Crc32c {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 32,
+            name: "CRC-32C",
+            extendable_output: false,
+            crc: true,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Crc32cDigest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Crc32c;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Crc32c, empty, U8_EMPTY, "00000000");
+    test_algorithm!(Crc32c, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "8a9136aa");
+    test_algorithm!(Crc32c, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "2c333a19");
+    test_algorithm!(Crc32c, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "62a8ab43");
+    test_algorithm!(Crc32c, one_byte, U8_1_BYTE, "064ad42f");
+    test_algorithm!(Crc32c, two_bytes, U8_2_BYTES, "955ab167");
+    test_algorithm!(Crc32c, three_bytes, U8_3_BYTES, "aa88aa8f");
+    test_algorithm!(Crc32c, four_bytes, U8_4_BYTES, "f4830761");
+    test_algorithm!(Crc32c, ascending, U8_32_ASCENDING, "8e4acb3e");
+    test_algorithm!(Crc32c, descending, U8_32_DESCENDING, "0b504ff4");
+    test_algorithm_s!(Crc32c, hi, S_HI, "f59dd9c2");
+    test_algorithm_s!(Crc32c, quick_brown_fox, S_QUICK_BROWN_FOX, "d01cd460");
+    test_algorithm_s!(Crc32c, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "5889f4df");
+    test_algorithm_s!(Crc32c, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "703e75b7");
+    test_algorithm_s!(Crc32c, check, "123456789", "e3069283");
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U8;
+
+checksum_digest!(Crc64EcmaDigest, crc::Crc<u64>, U8, &crc::CRC_64_ECMA_182);
+
+/// CRC-64 as specified by ECMA-182, without the inversions CRC-64/XZ adds.
+pub struct Crc64Ecma {}
+
+impl Crc64Ecma {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 64,
+            name: "CRC-64/ECMA-182",
+            extendable_output: false,
+            crc: true,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Crc64EcmaDigest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Crc64Ecma;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Crc64Ecma, empty, U8_EMPTY, "0000000000000000");
+    test_algorithm!(Crc64Ecma, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "0000000000000000");
+    test_algorithm!(Crc64Ecma, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "8dc3064a1fc2b81a");
+    test_algorithm!(Crc64Ecma, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "c395ae61ff6ce004");
+    test_algorithm!(Crc64Ecma, one_byte, U8_1_BYTE, "1dbc74446c07f0bd");
+    test_algorithm!(Crc64Ecma, two_bytes, U8_2_BYTES, "7ca0d44cf2c04ac4");
+    test_algorithm!(Crc64Ecma, three_bytes, U8_3_BYTES, "327cab19e593c83b");
+    test_algorithm!(Crc64Ecma, four_bytes, U8_4_BYTES, "3484553744b1c514");
+    test_algorithm!(Crc64Ecma, ascending, U8_32_ASCENDING, "3acf24f8ad05cf07");
+    test_algorithm!(Crc64Ecma, descending, U8_32_DESCENDING, "f76d38b9ae34eae1");
+    test_algorithm_s!(Crc64Ecma, hi, S_HI, "601f3dd9274312ce");
+    test_algorithm_s!(Crc64Ecma, quick_brown_fox, S_QUICK_BROWN_FOX, "9ce340539e897e72");
+    test_algorithm_s!(Crc64Ecma, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "bf556b33393b02db");
+    test_algorithm_s!(Crc64Ecma, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "d67c5b27268ac0cf");
+    test_algorithm_s!(Crc64Ecma, check, "123456789", "6c40df5f0b497347");
+}
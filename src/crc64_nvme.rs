@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U8;
+
+checksum_digest!(Crc64NvmeDigest, crc::Crc<u64>, U8, &crc::CRC_64_NVME);
+
+/// CRC-64/NVME, used by NVMe and Amazon S3 checksums.
+pub struct Crc64Nvme {}
+
+impl Crc64Nvme {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 64,
+            name: "CRC-64/NVME",
+            extendable_output: false,
+            crc: true,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Crc64NvmeDigest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Crc64Nvme;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Crc64Nvme, empty, U8_EMPTY, "0000000000000000");
+    test_algorithm!(Crc64Nvme, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "cf3473434d4ecf3b");
+    test_algorithm!(Crc64Nvme, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "ed727679f53b5e82");
+    test_algorithm!(Crc64Nvme, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "a0a06974c34d63c4");
+    test_algorithm!(Crc64Nvme, one_byte, U8_1_BYTE, "454d61362c8a4ab3");
+    test_algorithm!(Crc64Nvme, two_bytes, U8_2_BYTES, "35ee872db30a80e3");
+    test_algorithm!(Crc64Nvme, three_bytes, U8_3_BYTES, "a500cb93a65281b0");
+    test_algorithm!(Crc64Nvme, four_bytes, U8_4_BYTES, "c3ed1a0db029601c");
+    test_algorithm!(Crc64Nvme, ascending, U8_32_ASCENDING, "20bb5f5d5519e9ab");
+    test_algorithm!(Crc64Nvme, descending, U8_32_DESCENDING, "11720a1cacba8c2e");
+    test_algorithm_s!(Crc64Nvme, hi, S_HI, "a34734257cf15841");
+    test_algorithm_s!(Crc64Nvme, quick_brown_fox, S_QUICK_BROWN_FOX, "d0266d237e43ca90");
+    test_algorithm_s!(Crc64Nvme, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "6f47171ce0188e51");
+    test_algorithm_s!(Crc64Nvme, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "3c286fbbe2a9bcfa");
+    test_algorithm_s!(Crc64Nvme, check, "123456789", "ae8b14860a799888");
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U8;
+
+checksum_digest!(Crc64XzDigest, crc::Crc<u64>, U8, &crc::CRC_64_XZ);
+
+/// CRC-64/XZ, also known as CRC-64/GO-ECMA, used by xz and Go's `hash/crc64` with the ECMA table.
+pub struct Crc64Xz {}
+
+impl Crc64Xz {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 64,
+            name: "CRC-64/XZ",
+            extendable_output: false,
+            crc: true,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Crc64XzDigest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Crc64Xz;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Crc64Xz, empty, U8_EMPTY, "0000000000000000");
+    test_algorithm!(Crc64Xz, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "c95af8617cd5330c");
+    test_algorithm!(Crc64Xz, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "b1408d66a8c0597e");
+    test_algorithm!(Crc64Xz, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "e95dce9efaa09acf");
+    test_algorithm!(Crc64Xz, one_byte, U8_1_BYTE, "48d247f3d1ea7536");
+    test_algorithm!(Crc64Xz, two_bytes, U8_2_BYTES, "ffa187f01b11171e");
+    test_algorithm!(Crc64Xz, three_bytes, U8_3_BYTES, "4d1eea4c3c6d5857");
+    test_algorithm!(Crc64Xz, four_bytes, U8_4_BYTES, "82a875c01452bbd0");
+    test_algorithm!(Crc64Xz, ascending, U8_32_ASCENDING, "c331e02d4beaa6fc");
+    test_algorithm!(Crc64Xz, descending, U8_32_DESCENDING, "58e6aabdb5773bec");
+    test_algorithm_s!(Crc64Xz, hi, S_HI, "48a5cabc1138a9fe");
+    test_algorithm_s!(Crc64Xz, quick_brown_fox, S_QUICK_BROWN_FOX, "b77201ed88abe598");
+    test_algorithm_s!(Crc64Xz, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "5f691388a71b5dec");
+    test_algorithm_s!(Crc64Xz, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "0f34f1b1386a418e");
+    test_algorithm_s!(Crc64Xz, check, "123456789", "995dc9bbdf1939fa");
+}
@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use data_encoding::{BASE32, BASE32_NOPAD, BASE64, BASE64URL, BASE64URL_NOPAD, BASE64_NOPAD};
+
+/// The ways a provided digest may be written out as text.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DigestEncoding {
+    /// Case-insensitive hexadecimal, as written by `sha256sum` and most download pages
+    Hex,
+    /// The base32 variant used by Nix, which has its own alphabet and byte order
+    NixBase32,
+    /// Case-insensitive RFC 4648 base32, e.g. as used in some S3 and Java tooling
+    Base32,
+    /// Standard RFC 4648 base64, e.g. as used by AWS, GCS and npm
+    Base64,
+    /// URL and filename safe RFC 4648 base64
+    Base64Url,
+}
+
+impl DigestEncoding {
+    /// All encodings, in the order they are tried when detecting the encoding of a digest.  Nix base32 only uses
+    /// lowercase letters, so it's tried before RFC 4648 base32, which could otherwise also decode it.
+    pub const ALL: [DigestEncoding; 5] = [
+        DigestEncoding::Hex,
+        DigestEncoding::NixBase32,
+        DigestEncoding::Base32,
+        DigestEncoding::Base64,
+        DigestEncoding::Base64Url,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DigestEncoding::Hex => "hex",
+            DigestEncoding::NixBase32 => "Nix base32",
+            DigestEncoding::Base32 => "base32",
+            DigestEncoding::Base64 => "base64",
+            DigestEncoding::Base64Url => "URL-safe base64",
+        }
+    }
+
+    /// Decodes `encoded`, which may or may not be padded.  Returns `None` if it isn't valid in this encoding.
+    pub fn decode(&self, encoded: &str) -> Option<Vec<u8>> {
+        let input = encoded.as_bytes();
+        match self {
+            DigestEncoding::Hex => hex::decode(encoded).ok(),
+            DigestEncoding::NixBase32 => decode_nix_base32(encoded),
+            DigestEncoding::Base32 => {
+                let input = encoded.to_ascii_uppercase();
+                let input = input.as_bytes();
+                BASE32.decode(input).or_else(|_| BASE32_NOPAD.decode(input)).ok()
+            }
+            DigestEncoding::Base64 => BASE64.decode(input).or_else(|_| BASE64_NOPAD.decode(input)).ok(),
+            DigestEncoding::Base64Url => BASE64URL.decode(input).or_else(|_| BASE64URL_NOPAD.decode(input)).ok(),
+        }
+    }
+}
+
+/// A provided digest decoded into its raw bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedDigest {
+    pub encoding: DigestEncoding,
+    pub bytes: Vec<u8>,
+}
+
+impl DecodedDigest {
+    pub fn bit_len(&self) -> usize {
+        self.bytes.len() * 8
+    }
+}
+
+/// Detects the encoding of `encoded` and decodes it.  Because the alphabets overlap, encodings are tried in the order
+/// of `DigestEncoding::ALL` and the first that decodes successfully wins.  For example, a string made up only of hex
+/// digits is always treated as hex even though it is also valid base64.
+pub fn decode_digest(encoded: &str) -> Option<DecodedDigest> {
+    if encoded.is_empty() {
+        return None;
+    }
+    DigestEncoding::ALL.iter().find_map(|encoding| {
+        encoding.decode(encoded).map(|bytes| DecodedDigest {
+            encoding: *encoding,
+            bytes,
+        })
+    })
+}
+
+/// The ways a CRC is written.  Its digest is big-endian like any other, but `cksum` and Java's `CRC32.getValue()`
+/// print it as a decimal integer and some Windows utilities print the bytes in little-endian order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CrcRepresentation {
+    /// Big-endian bytes in any of the `DigestEncoding`s, usually hex
+    BigEndian,
+    /// Little-endian bytes in any of the `DigestEncoding`s, usually hex
+    LittleEndian,
+    /// A decimal integer
+    Decimal,
+}
+
+impl CrcRepresentation {
+    /// All representations, in the order they are tried when verifying a CRC.
+    pub const ALL: [CrcRepresentation; 3] = [
+        CrcRepresentation::BigEndian,
+        CrcRepresentation::LittleEndian,
+        CrcRepresentation::Decimal,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CrcRepresentation::BigEndian => "big-endian",
+            CrcRepresentation::LittleEndian => "little-endian",
+            CrcRepresentation::Decimal => "decimal",
+        }
+    }
+
+    /// Writes a CRC, given as the big-endian bytes of its digest, in this representation.  Both byte orders are
+    /// written as hex.
+    pub fn encode(&self, crc: &[u8]) -> String {
+        match self {
+            CrcRepresentation::BigEndian => hex::encode(crc),
+            CrcRepresentation::LittleEndian => hex::encode(crc.iter().rev().copied().collect::<Vec<u8>>()),
+            CrcRepresentation::Decimal => crc.iter().fold(0u128, |n, b| n << 8 | u128::from(*b)).to_string(),
+        }
+    }
+
+    /// Decodes a CRC of `size` bytes written in this representation into the big-endian bytes of its digest.  Returns
+    /// `None` if `encoded` isn't a CRC of that size written this way.
+    pub fn decode(&self, encoded: &str, size: usize) -> Option<Vec<u8>> {
+        let bytes = match self {
+            CrcRepresentation::BigEndian => decode_digest(encoded)?.bytes,
+            CrcRepresentation::LittleEndian => decode_digest(encoded)?.bytes.into_iter().rev().collect(),
+            CrcRepresentation::Decimal => {
+                let n: u128 = encoded.parse().ok().filter(|_| is_decimal(encoded))?;
+                let bytes = n.to_be_bytes();
+                let (high, low) = bytes.split_at(bytes.len().checked_sub(size)?);
+                if high.iter().any(|b| *b != 0) {
+                    return None;
+                }
+                low.to_vec()
+            }
+        };
+        Some(bytes).filter(|b| b.len() == size)
+    }
+}
+
+/// Whether `encoded` is made up only of decimal digits, and so might be a CRC written in decimal.
+pub fn is_decimal(encoded: &str) -> bool {
+    !encoded.is_empty() && encoded.bytes().all(|b| b.is_ascii_digit())
+}
+
+const NIX_BASE32_CHARS: &[u8; 32] = b"0123456789abcdfghijklmnpqrsvwxyz";
+
+/// Nix's base32 writes the last 5 bits of the digest first and omits the letters e, o, u and t.
+fn decode_nix_base32(encoded: &str) -> Option<Vec<u8>> {
+    let size = encoded.len() * 5 / 8;
+    if size == 0 || (size * 8 - 1) / 5 + 1 != encoded.len() {
+        return None;
+    }
+    let mut bytes = vec![0u8; size];
+    for (n, c) in encoded.bytes().rev().enumerate() {
+        let digit = NIX_BASE32_CHARS.iter().position(|&d| d == c)? as u16;
+        let (i, j) = (n * 5 / 8, n * 5 % 8);
+        let shifted = digit << j;
+        bytes[i] |= shifted as u8;
+        let carry = (shifted >> 8) as u8;
+        match bytes.get_mut(i + 1) {
+            Some(next) => *next |= carry,
+            None if carry != 0 => return None,
+            None => {}
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_digest, is_decimal, CrcRepresentation, DigestEncoding};
+
+    const SHA256_QUICK_BROWN_FOX: &str = "531fd7e9f8f6d4b3836684a94b1b39e0966842dcf0ba251f8f8d774dad6e5ed9";
+    const MD5_QUICK_BROWN_FOX: &str = "6363fe744f74ee8f280958ab2f185dde";
+
+    fn assert_decodes(encoded: &str, encoding: DigestEncoding, expected_hex: &str) {
+        let decoded = decode_digest(encoded).unwrap();
+        assert_eq!(decoded.encoding, encoding, "Wrong encoding detected for {}", encoded);
+        assert_eq!(hex::encode(&decoded.bytes), expected_hex);
+    }
+
+    #[test]
+    fn hex() {
+        assert_decodes(&SHA256_QUICK_BROWN_FOX.to_uppercase(), DigestEncoding::Hex, SHA256_QUICK_BROWN_FOX);
+    }
+
+    #[test]
+    fn base64() {
+        let encoded = "Ux/X6fj21LODZoSpSxs54JZoQtzwuiUfj413Ta1uXtk=";
+        assert_decodes(encoded, DigestEncoding::Base64, SHA256_QUICK_BROWN_FOX);
+        assert_decodes(encoded.trim_end_matches('='), DigestEncoding::Base64, SHA256_QUICK_BROWN_FOX);
+        assert_decodes("Y2P+dE907o8oCVirLxhd3g==", DigestEncoding::Base64, MD5_QUICK_BROWN_FOX);
+    }
+
+    #[test]
+    fn base64_url() {
+        assert_decodes("Y2P-dE907o8oCVirLxhd3g==", DigestEncoding::Base64Url, MD5_QUICK_BROWN_FOX);
+        assert_decodes("Y2P-dE907o8oCVirLxhd3g", DigestEncoding::Base64Url, MD5_QUICK_BROWN_FOX);
+    }
+
+    #[test]
+    fn base32() {
+        let encoded = "KMP5P2PY63KLHA3GQSUUWGZZ4CLGQQW46C5CKH4PRV3U3LLOL3MQ====";
+        assert_decodes(encoded, DigestEncoding::Base32, SHA256_QUICK_BROWN_FOX);
+        assert_decodes(encoded.trim_end_matches('='), DigestEncoding::Base32, SHA256_QUICK_BROWN_FOX);
+        assert_decodes(&encoded.to_lowercase(), DigestEncoding::Base32, SHA256_QUICK_BROWN_FOX);
+        assert_decodes("mnr745cpotxi6kajlcvs6gc53y", DigestEncoding::Base32, MD5_QUICK_BROWN_FOX);
+    }
+
+    #[test]
+    fn nix_base32() {
+        assert_decodes(
+            "0mdqa9w1p6cmli6976v4wi0sw9r4p5prkj7lzfd1877wk11c9c73",
+            DigestEncoding::NixBase32,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        );
+        assert_decodes(
+            "1naydsnlsxwdiwgjbfphvi16i5p074dlpac4cs1v7m7nz3lxf7sk",
+            DigestEncoding::NixBase32,
+            SHA256_QUICK_BROWN_FOX,
+        );
+        assert_decodes("6yblc2zasq14l8zvkl9xsgwqv3", DigestEncoding::NixBase32, MD5_QUICK_BROWN_FOX);
+    }
+
+    #[test]
+    fn crc_representations() {
+        let crc = [0x37, 0x7a, 0x60, 0x11];
+        for (representation, encoded) in [
+            (CrcRepresentation::BigEndian, "377a6011"),
+            (CrcRepresentation::LittleEndian, "11607a37"),
+            (CrcRepresentation::Decimal, "930766865"),
+        ] {
+            assert_eq!(representation.encode(&crc), encoded);
+            assert_eq!(representation.decode(encoded, 4), Some(crc.to_vec()), "{}", representation.name());
+            assert_eq!(representation.decode(encoded, 8).is_some(), representation == CrcRepresentation::Decimal);
+        }
+        assert_eq!(CrcRepresentation::LittleEndian.decode("EWB6Nw==", 4), Some(crc.to_vec()));
+        assert_eq!(CrcRepresentation::Decimal.decode("65535", 2), Some(vec![0xff, 0xff]));
+        assert_eq!(CrcRepresentation::Decimal.decode("65536", 2), None);
+        assert_eq!(CrcRepresentation::Decimal.decode("+1", 2), None);
+        assert!(is_decimal("0123"));
+        assert!(!is_decimal("12a"));
+        assert!(!is_decimal(""));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(decode_digest(""), None);
+        assert_eq!(decode_digest("not a digest"), None);
+        assert_eq!(decode_digest("Ux/X6fj21LODZoSpSxs54JZoQtzwuiUfj413Ta1uXtk=="), None);
+        assert_eq!(DigestEncoding::NixBase32.decode("e3b0"), None);
+        assert_eq!(DigestEncoding::NixBase32.decode("0"), None);
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::Strength;
+use std::fmt::{Display, Formatter};
+
+/// Everything that can go wrong while verifying content against a digest.
+#[derive(Debug)]
+pub enum Error {
+    /// No supported algorithm produces a digest of this many bits.
+    UnsupportedDigestLength { bits: usize },
+    /// Fewer bytes were read than the file's length.
+    ShortRead { expected: u64, actual: u64 },
+    /// A digest that isn't hexadecimal, base64 or base32, or a digest string that can't be parsed at all.
+    InvalidEncoding(String),
+    /// An algorithm name that doesn't match any of the supported algorithms.
+    UnknownAlgorithm { name: String, supported: Vec<String> },
+    /// A line of a checksum file that isn't in any of the supported formats.
+    MalformedChecksumLine { line_number: usize },
+    /// Reading the content or a file failed.
+    Io(std::io::Error),
+    /// The content doesn't match the provided digest.
+    Mismatch,
+    /// The content only matches digests weaker than `min_strength`.
+    TooWeak { min_strength: Strength },
+    /// A command line argument or option that can't be used, e.g. an unknown strength or an empty key.
+    InvalidArgument(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnsupportedDigestLength { bits } => {
+                write!(f, "No supported algorithms for digest of size {} bits", bits)
+            }
+            Error::ShortRead { expected, actual } => write!(
+                f,
+                "Wasn't able to read full file length of {} bytes.  Only read {}.",
+                expected, actual
+            ),
+            Error::InvalidEncoding(digest) => write!(
+                f,
+                "'{}' does not seem to be a valid hexadecimal, base64 or base32 digest",
+                digest
+            ),
+            Error::UnknownAlgorithm { name, supported } => write!(
+                f,
+                "Unknown algorithm '{}'.  Supported algorithms are {}.",
+                name,
+                supported.join(", ")
+            ),
+            Error::MalformedChecksumLine { line_number } => {
+                write!(f, "Line {} is not a properly formatted checksum line", line_number)
+            }
+            Error::Io(e) => e.fmt(f),
+            Error::Mismatch => write!(f, "The content doesn't match the provided digest"),
+            Error::TooWeak { min_strength } => write!(
+                f,
+                "The content only matches digests weaker than the minimum strength, {}",
+                min_strength.name()
+            ),
+            Error::InvalidArgument(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+
+    #[test]
+    fn io_errors_are_wrapped() {
+        let e = Error::from(std::io::Error::new(std::io::ErrorKind::NotFound, "gone"));
+        assert!(matches!(&e, Error::Io(io) if io.kind() == std::io::ErrorKind::NotFound));
+        assert_eq!(e.to_string(), "gone");
+        assert!(std::error::Error::source(&e).is_some());
+    }
+
+    #[test]
+    fn short_read_message() {
+        let e = Error::ShortRead {
+            expected: 10,
+            actual: 4,
+        };
+        assert_eq!(
+            e.to_string(),
+            "Wasn't able to read full file length of 10 bytes.  Only read 4."
+        );
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U4;
+
+const OFFSET_BASIS: u32 = 0x811c_9dc5;
+const PRIME: u32 = 0x0100_0193;
+
+#[derive(Clone)]
+struct Fnv1a32State(u32);
+
+impl Fnv1a32State {
+    fn update(&mut self, input: &[u8]) {
+        for b in input {
+            self.0 = (self.0 ^ u32::from(*b)).wrapping_mul(PRIME);
+        }
+    }
+
+    fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+checksum_digest!(
+    Fnv1a32Digest,
+    Fnv1a32State,
+    U4,
+    Fnv1a32State(OFFSET_BASIS),
+    Fnv1a32State::update,
+    Fnv1a32State::value
+);
+
+/// The 32-bit FNV-1a hash.
+pub struct Fnv1a32 {}
+
+impl Fnv1a32 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 32,
+            name: "FNV-1a-32",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Fnv1a32Digest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fnv1a32;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Fnv1a32, empty, U8_EMPTY, "811c9dc5");
+    test_algorithm!(Fnv1a32, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "0b2ae445");
+    test_algorithm!(Fnv1a32, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "1208d365");
+    test_algorithm!(Fnv1a32, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "5b517625");
+    test_algorithm!(Fnv1a32, one_byte, U8_1_BYTE, "e00c22e0");
+    test_algorithm!(Fnv1a32, two_bytes, U8_2_BYTES, "8592c7b8");
+    test_algorithm!(Fnv1a32, three_bytes, U8_3_BYTES, "6db5f4ce");
+    test_algorithm!(Fnv1a32, four_bytes, U8_4_BYTES, "ee60cc12");
+    test_algorithm!(Fnv1a32, ascending, U8_32_ASCENDING, "e745ef65");
+    test_algorithm!(Fnv1a32, descending, U8_32_DESCENDING, "cf823385");
+    test_algorithm_s!(Fnv1a32, hi, S_HI, "683af69a");
+    test_algorithm_s!(Fnv1a32, quick_brown_fox, S_QUICK_BROWN_FOX, "002ad0de");
+    test_algorithm_s!(Fnv1a32, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "1766bdbc");
+    test_algorithm_s!(Fnv1a32, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "423028cf");
+    test_algorithm!(Fnv1a32, six_thousand_bytes_of_all_ones, U8_6000_ALL_ONES, "452bde15");
+    test_algorithm_s!(Fnv1a32, check, "123456789", "bb86b11c");
+}
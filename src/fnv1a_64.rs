@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U8;
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+#[derive(Clone)]
+struct Fnv1a64State(u64);
+
+impl Fnv1a64State {
+    fn update(&mut self, input: &[u8]) {
+        for b in input {
+            self.0 = (self.0 ^ u64::from(*b)).wrapping_mul(PRIME);
+        }
+    }
+
+    fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+checksum_digest!(
+    Fnv1a64Digest,
+    Fnv1a64State,
+    U8,
+    Fnv1a64State(OFFSET_BASIS),
+    Fnv1a64State::update,
+    Fnv1a64State::value
+);
+
+/// The 64-bit FNV-1a hash.
+pub struct Fnv1a64 {}
+
+impl Fnv1a64 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 64,
+            name: "FNV-1a-64",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Fnv1a64Digest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fnv1a64;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Fnv1a64, empty, U8_EMPTY, "cbf29ce484222325");
+    test_algorithm!(Fnv1a64, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "0c8210784d8af5a5");
+    test_algorithm!(Fnv1a64, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "cba3930f97ea0ec5");
+    test_algorithm!(Fnv1a64, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "9ac5e12119fd3f85");
+    test_algorithm!(Fnv1a64, one_byte, U8_1_BYTE, "af63d84c8601e5c0");
+    test_algorithm!(Fnv1a64, two_bytes, U8_2_BYTES, "09867d07b60c5d38");
+    test_algorithm!(Fnv1a64, three_bytes, U8_3_BYTES, "c56ade1aa49a478e");
+    test_algorithm!(Fnv1a64, four_bytes, U8_4_BYTES, "84d8b1c98e6c8b72");
+    test_algorithm!(Fnv1a64, ascending, U8_32_ASCENDING, "cb4e0588c4eb08c5");
+    test_algorithm!(Fnv1a64, descending, U8_32_DESCENDING, "146e5c5a733f80a5");
+    test_algorithm_s!(Fnv1a64, hi, S_HI, "08ba5f07b55ec3da");
+    test_algorithm_s!(Fnv1a64, quick_brown_fox, S_QUICK_BROWN_FOX, "057a8291edbf7d5e");
+    test_algorithm_s!(Fnv1a64, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "0ea931f6fc61f5bc");
+    test_algorithm_s!(Fnv1a64, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "4d57a7612497de0f");
+    test_algorithm!(Fnv1a64, six_thousand_bytes_of_all_ones, U8_6000_ALL_ONES, "f96dd8c4a6ff1c75");
+    test_algorithm_s!(Fnv1a64, check, "123456789", "06d5573923c6cdfc");
+}
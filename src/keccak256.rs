@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+pub struct Keccak256 {}
+
+impl Keccak256 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 256,
+            name: "Keccak-256",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha3::Keccak256>,
+            keying: hmac::<sha3::Keccak256>("HMAC-Keccak-256"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Keccak256;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Keccak256, empty, U8_EMPTY, "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+    test_algorithm!(Keccak256, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563");
+    test_algorithm!(Keccak256, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "c231a8d74373ed73d23af6d0fc8cc6d2555d2e80622950665723e7eba5c1f1b9");
+    test_algorithm!(Keccak256, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "a9c584056064687e149968cbab758a3376d22aedc6a55823d1b3ecbee81b8fb9");
+    test_algorithm!(Keccak256, one_byte, U8_1_BYTE, "a8982c89d80987fb9a510e25981ee9170206be21af3c8e0eb312ef1d3382e761");
+    test_algorithm!(Keccak256, two_bytes, U8_2_BYTES, "15887ddf60e2e6b78e963b6dd4e93041dc8206450f1baa784eb0f6d704a44149");
+    test_algorithm!(Keccak256, three_bytes, U8_3_BYTES, "c4168ac396c0d1bfc779c96749e10027c095e41eb533f836f89c1a2b37b481d6");
+    test_algorithm!(Keccak256, four_bytes, U8_4_BYTES, "6da7fc56ad53ef683ae914841463efdf1d40c20f2729d1b7cc26979c3976fc8b");
+    test_algorithm!(Keccak256, ascending, U8_32_ASCENDING, "52b3f53ff196a28e7d2d01283ef9427070bda64128fb5630b97b6ab17a8ff0a8");
+    test_algorithm!(Keccak256, descending, U8_32_DESCENDING, "3842a4d5df2e63d851450cf18e565a7dc51c288e2edf0f10f493e6a67d6bc252");
+    test_algorithm_s!(Keccak256, hi, S_HI, "7624778dedc75f8b322b9fa1632a610d40b85e106c7d9bf0e743a9ce291b9c6f");
+    test_algorithm_s!(Keccak256, quick_brown_fox, S_QUICK_BROWN_FOX, "38a2e05f6baae370b5b03d5cd9e87064dadea7d93d04931b97ad1bc05c7da524");
+    test_algorithm_s!(Keccak256, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "625098865736a65252b8f623034bdefbc46d2e073076cc313f5cf9786d910259");
+    test_algorithm_s!(Keccak256, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "8d14913e7a24e69479f9a63d3f527e2abb6859d83591acba5b2e3dffd4aaef11");
+    test_keyed_algorithm_s!(Keccak256, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "97cb14ff0eef995e70edf9b48b92c5b87fa444efddb959f0c7c2bb017f4f30e1");
+}
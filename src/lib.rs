@@ -1,14 +1,57 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT-0
 
-use std::io::{Read, Result, Write};
+#![allow(clippy::new_ret_no_self)]
 
-use digest::Digest;
+use std::io::{Read, Write};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "tokio")]
+pub use asynchronous::digest_all_async;
+pub use error::Error;
+pub use registry::Registry;
+pub use strength::Strength;
+
+use data_encoding::BASE64;
+use encoding::CrcRepresentation;
+use digest::core_api::BlockSizeUser;
+use digest::{
+    Digest, DynDigest, ExtendableOutputReset, FixedOutputReset, InvalidBufferSize, KeyInit, OutputSizeUser, Reset,
+    Update,
+};
+use hmac::SimpleHmac;
 
 #[derive(Clone)]
 pub struct CalculatedDigest {
     pub bytes_read: u64,
     pub algorithm_name: String,
-    pub digest: String,
+    pub digest: Vec<u8>,
+}
+
+impl CalculatedDigest {
+    pub fn to_hex(&self) -> String {
+        hex::encode(&self.digest)
+    }
+
+    /// Standard, padded base64, as used by Subresource Integrity metadata.
+    pub fn to_base64(&self) -> String {
+        BASE64.encode(&self.digest)
+    }
+
+    /// Whether the digest is `expected`, compared in constant time so that how long the comparison takes doesn't
+    /// reveal how much of `expected` is right.  Only the length of `expected` isn't kept secret.
+    pub fn verify(&self, expected: &[u8]) -> bool {
+        self.digest.ct_eq(expected).into()
+    }
+
+    /// Like `verify`, but for a CRC written as text in any of the `CrcRepresentation`s.  Returns the representation
+    /// that matched, if any.
+    pub fn verify_crc(&self, expected: &str) -> Option<CrcRepresentation> {
+        CrcRepresentation::ALL.into_iter().find(|representation| {
+            representation
+                .decode(expected, self.digest.len())
+                .is_some_and(|bytes| self.verify(&bytes))
+        })
+    }
 }
 
 //
@@ -16,36 +59,277 @@ pub struct CalculatedDigest {
 pub struct Algorithm<'a> {
     pub digest_bit_size: u16,
     pub name: &'a str,
-    digest_fn: fn(&Algorithm, &mut dyn Read) -> Result<CalculatedDigest>,
+    /// Whether this is an extendable-output function (XOF), like SHAKE, that can produce a digest of any length.
+    pub extendable_output: bool,
+    /// Whether the digest is a CRC, which may also be written in decimal or in little-endian byte order.
+    pub crc: bool,
+    /// How far a match can be trusted.  Checksums like CRCs and xxHash only detect accidental corruption, and MD5 and
+    /// SHA-1 are broken.  A keyed algorithm has the strength of its hash.
+    pub strength: Strength,
+    hasher_fn: fn(&Algorithm) -> Box<dyn DynDigest>,
+    keying: Option<Keying<'a>>,
+}
+
+/// How an algorithm calculates a digest with a secret key, along with the key once one is provided.
+#[derive(Copy, Clone)]
+struct Keying<'a> {
+    name: &'a str,
+    hasher_fn: fn(&[u8]) -> Option<Box<dyn DynDigest>>,
+    key: Option<&'a [u8]>,
 }
 
 impl<'a> Algorithm<'a> {
-    pub fn digest(&'a self, read: &mut dyn Read) -> Result<CalculatedDigest> {
-        (self.digest_fn)(&self, read)
+    /// The shortest digest an extendable-output algorithm is sized to.  Anything shorter has less collision resistance
+    /// than SHA-224, and would make every short string of hex a candidate.
+    pub const MIN_XOF_DIGEST_BIT_SIZE: u16 = 224;
+
+    pub fn digest(&'a self, read: &mut dyn Read) -> Result<CalculatedDigest, Error> {
+        let mut calculated = digest_all(std::slice::from_ref(self), read)?;
+        Ok(calculated.remove(0))
+    }
+
+    /// Returns a hasher that's fed content with `update` rather than reading it.
+    pub fn hasher(&self) -> DynHasher<'a> {
+        DynHasher {
+            algorithm: *self,
+            hasher: match self.keying {
+                Some(Keying {
+                    hasher_fn,
+                    key: Some(key),
+                    ..
+                }) => hasher_fn(key).expect("with_key only accepts usable keys"),
+                _ => (self.hasher_fn)(self),
+            },
+            bytes_read: 0,
+        }
+    }
+
+    /// Creates an algorithm named `name` from any fixed output size `digest` hasher, e.g. to register a custom
+    /// algorithm.  It's assumed to be `Strong`; set `strength` if it isn't.
+    pub fn from_digest<D: Update + FixedOutputReset + Reset + Clone + Default + 'static>(name: &'a str) -> Algorithm<'a> {
+        Algorithm {
+            digest_bit_size: u16::try_from(<D as OutputSizeUser>::output_size() * 8).unwrap_or(u16::MAX),
+            name,
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<D>,
+            keying: None,
+        }
+    }
+
+    /// Like `from_digest`, but for a block hash, so that it can also calculate an HMAC named `hmac_name` once it's
+    /// given a key with `with_key`.
+    pub fn from_block_digest<D: Digest + BlockSizeUser + FixedOutputReset + Reset + Clone + Default + 'static>(
+        name: &'a str,
+        hmac_name: &'a str,
+    ) -> Algorithm<'a> {
+        Algorithm {
+            keying: hmac::<D>(hmac_name),
+            ..Algorithm::from_digest::<D>(name)
+        }
+    }
+
+    /// Returns a copy that calculates a keyed digest using `key`: an HMAC for block hashes like SHA-256, or the
+    /// keyed modes of BLAKE2 and BLAKE3.  Returns `None` if the algorithm can't be keyed, is already keyed, or
+    /// doesn't accept a key of this length; BLAKE3 keys are exactly 32 bytes and BLAKE2 keys are at most a block.
+    pub fn with_key(&self, key: &'a [u8]) -> Option<Algorithm<'a>> {
+        let keying = self.keying.filter(|k| k.key.is_none())?;
+        (keying.hasher_fn)(key)?;
+        Some(Algorithm {
+            name: keying.name,
+            keying: Some(Keying {
+                key: Some(key),
+                ..keying
+            }),
+            ..*self
+        })
+    }
+
+    /// Whether this algorithm was given a key with `with_key`.
+    pub fn is_keyed(&self) -> bool {
+        self.keying.is_some_and(|k| k.key.is_some())
+    }
+
+    /// Returns a copy of an extendable-output algorithm that produces a digest of `digest_bit_size` bits.  Returns
+    /// `None` if the algorithm's output size is fixed, `digest_bit_size` isn't a whole number of bytes or it's less
+    /// than `MIN_XOF_DIGEST_BIT_SIZE`, so the copy is as strong as the algorithm.
+    pub fn with_digest_bit_size(&self, digest_bit_size: u16) -> Option<Algorithm<'a>> {
+        if !self.extendable_output
+            || digest_bit_size < Self::MIN_XOF_DIGEST_BIT_SIZE
+            || !digest_bit_size.is_multiple_of(8)
+        {
+            return None;
+        }
+        Some(Algorithm {
+            digest_bit_size,
+            ..*self
+        })
+    }
+}
+
+/// Calculates a digest for each of the `algorithms` in a single pass over `read`.  Every buffer read is fanned out to
+/// all of the hashers, so the content is only read once no matter how many algorithms are requested.  The results are
+/// returned in the same order as `algorithms`.
+pub fn digest_all(algorithms: &[Algorithm], read: &mut dyn Read) -> Result<Vec<CalculatedDigest>, Error> {
+    let mut hashers = MultiHasher {
+        hashers: algorithms.iter().map(|a| a.hasher()).collect(),
+    };
+    std::io::copy(read, &mut hashers)?;
+    Ok(hashers.hashers.into_iter().map(|h| h.finalize()).collect())
+}
+
+struct MultiHasher<'a> {
+    hashers: Vec<DynHasher<'a>>,
+}
+
+impl Write for MultiHasher<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for hasher in self.hashers.iter_mut() {
+            hasher.update(buf);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Incrementally calculates a digest of content that arrives in pieces, e.g. chunks received from the network, rather
+/// than through a `Read`.  Cloning captures the intermediate state, so a digest of a prefix can be finalized while
+/// hashing continues.
+#[derive(Clone)]
+pub struct DynHasher<'a> {
+    algorithm: Algorithm<'a>,
+    hasher: Box<dyn DynDigest>,
+    bytes_read: u64,
+}
+
+impl<'a> DynHasher<'a> {
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+        self.bytes_read += data.len() as u64;
+    }
+
+    pub fn finalize(self) -> CalculatedDigest {
+        to_calculated_digest(&self.algorithm, self.bytes_read, &self.hasher.finalize())
+    }
+
+    /// Finalizes the digest of everything so far and resets the hasher to start over.
+    pub fn finalize_reset(&mut self) -> CalculatedDigest {
+        let calculated = to_calculated_digest(&self.algorithm, self.bytes_read, &self.hasher.finalize_reset());
+        self.bytes_read = 0;
+        calculated
+    }
+
+    pub fn reset(&mut self) {
+        self.hasher.reset();
+        self.bytes_read = 0;
+    }
+
+    pub fn algorithm(&self) -> &Algorithm<'a> {
+        &self.algorithm
+    }
+
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+}
+
+impl Write for DynHasher<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
     }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn new_hasher<D: Update + FixedOutputReset + Reset + Clone + Default + 'static>(_: &Algorithm) -> Box<dyn DynDigest> {
+    Box::<D>::default()
 }
 
-fn calculated_digest<D: Digest + Write>(a: &Algorithm, read: &mut dyn Read) -> Result<CalculatedDigest> {
-    let mut digest = D::new();
-    let bytes_read = std::io::copy(read, &mut digest);
-    bytes_read.map(|br| CalculatedDigest {
-        algorithm_name: String::from(a.name).clone(),
-        bytes_read: br,
-        digest: hex::encode(digest.finalize().as_slice()),
+/// Keying for an HMAC named `name` built on the block hash `D`.
+fn hmac<D: Digest + BlockSizeUser + FixedOutputReset + Reset + Clone + 'static>(name: &str) -> Option<Keying<'_>> {
+    Some(Keying {
+        name,
+        hasher_fn: new_mac_hasher::<SimpleHmac<D>>,
+        key: None,
     })
 }
 
-fn to_calculated_digest(a: &Algorithm, bytes_read: Result<u64>, dr: &[u8]) -> Result<CalculatedDigest> {
-    bytes_read.map(|br| CalculatedDigest {
-        algorithm_name: String::from(a.name).clone(),
-        bytes_read: br,
-        digest: hex::encode(dr),
+fn new_mac_hasher<M: KeyInit + Update + FixedOutputReset + Reset + Clone + 'static>(
+    key: &[u8],
+) -> Option<Box<dyn DynDigest>> {
+    Some(Box::new(M::new_from_slice(key).ok()?))
+}
+
+fn new_xof_hasher<D: ExtendableOutputReset + Clone + Default + 'static>(a: &Algorithm) -> Box<dyn DynDigest> {
+    Box::new(XofDigest {
+        hasher: D::default(),
+        output_size: usize::from(a.digest_bit_size / 8),
     })
 }
 
+/// Adapts an extendable-output function to `DynDigest` by reading a fixed number of bytes of output.
+#[derive(Clone)]
+struct XofDigest<D> {
+    hasher: D,
+    output_size: usize,
+}
+
+impl<D: ExtendableOutputReset + Clone + 'static> DynDigest for XofDigest<D> {
+    fn update(&mut self, data: &[u8]) {
+        Update::update(&mut self.hasher, data);
+    }
+
+    fn finalize_into(self, buf: &mut [u8]) -> std::result::Result<(), InvalidBufferSize> {
+        if buf.len() != self.output_size {
+            return Err(InvalidBufferSize);
+        }
+        self.hasher.finalize_xof_into(buf);
+        Ok(())
+    }
+
+    fn finalize_into_reset(&mut self, buf: &mut [u8]) -> std::result::Result<(), InvalidBufferSize> {
+        if buf.len() != self.output_size {
+            return Err(InvalidBufferSize);
+        }
+        self.hasher.finalize_xof_reset_into(buf);
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        Reset::reset(&mut self.hasher);
+    }
+
+    fn output_size(&self) -> usize {
+        self.output_size
+    }
+
+    fn box_clone(&self) -> Box<dyn DynDigest> {
+        Box::new(self.clone())
+    }
+}
+
+fn to_calculated_digest(a: &Algorithm, bytes_read: u64, dr: &[u8]) -> CalculatedDigest {
+    CalculatedDigest {
+        algorithm_name: String::from(a.name),
+        bytes_read,
+        digest: dr.to_vec(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::Algorithm;
+    use crate::encoding::CrcRepresentation;
+    use crate::{
+        blake3::Blake3, cksum::Cksum, crc32::Crc32, digest_all, md5::Md5, ripemd160::Ripemd160, sha1::Sha1,
+        sha256::Sha256, sha512::Sha512, shake128::Shake128, Algorithm, Strength,
+    };
 
     pub fn test_algorithm(
         algorithm: &Algorithm,
@@ -53,14 +337,34 @@ mod tests {
         expected_length: u64,
         expected_value: &str,
     ) {
-        let calculated = algorithm.digest(data).unwrap();
+        let mut content = Vec::new();
+        data.read_to_end(&mut content).unwrap();
+        let calculated = algorithm.digest(&mut content.as_slice()).unwrap();
         assert_eq!(calculated.bytes_read, expected_length);
         assert_eq!(calculated.algorithm_name, algorithm.name);
         assert_eq!(
-            calculated.digest, expected_value,
+            calculated.to_hex(),
+            expected_value,
             "Expected {}, got {}",
-            expected_value, calculated.digest
+            expected_value,
+            calculated.to_hex()
         );
+
+        #[cfg(feature = "tokio")]
+        {
+            let calculated = block_on(algorithm.digest_async(&mut content.as_slice())).unwrap();
+            assert_eq!(calculated.bytes_read, expected_length);
+            assert_eq!(calculated.algorithm_name, algorithm.name);
+            assert_eq!(calculated.to_hex(), expected_value, "Async digest differs");
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
     }
 
     #[macro_export]
@@ -70,7 +374,7 @@ mod tests {
             #[test]
             fn $name() {
                 println!("'{:?}'", $input);
-                crate::tests::test_algorithm(
+                $crate::tests::test_algorithm(
                     &(<$algorithm>::new()),
                     &mut std::io::Cursor::new($input),
                     $input.len() as u64,
@@ -80,12 +384,30 @@ mod tests {
         };
     }
 
+    pub fn test_keyed_algorithm(algorithm: &Algorithm, key: &[u8], data: &str, expected_value: &str) {
+        let keyed = algorithm.with_key(key).unwrap();
+        assert!(keyed.is_keyed());
+        assert_eq!(keyed.digest_bit_size, algorithm.digest_bit_size);
+        assert!(keyed.with_key(key).is_none());
+        test_algorithm(&keyed, &mut data.as_bytes(), data.len() as u64, expected_value);
+    }
+
+    #[macro_export]
+    macro_rules! test_keyed_algorithm_s {
+        ($algorithm:ident, $name:ident, $key:expr, $str:expr, $expected_digest:expr) => {
+            #[test]
+            fn $name() {
+                $crate::tests::test_keyed_algorithm(&(<$algorithm>::new()), $key, $str, $expected_digest)
+            }
+        };
+    }
+
     #[macro_export]
     macro_rules! test_algorithm_s {
         ($algorithm:ident, $name:ident, $str:expr, $expected_digest:expr) => {
             #[test]
             fn $name() {
-                crate::tests::test_algorithm(
+                $crate::tests::test_algorithm(
                     &(<$algorithm>::new()),
                     &mut ($str).as_bytes(),
                     $str.len() as u64,
@@ -99,6 +421,7 @@ mod tests {
     pub const U8_32_ALL_ZEROS: [u8; 32] = [0x00; 32];
     pub const U8_32_HALF_ONES: [u8; 32] = [0x0f; 32];
     pub const U8_32_ALL_ONES: [u8; 32] = [0xff; 32];
+    pub const U8_6000_ALL_ONES: [u8; 6000] = [0xff; 6000];
     pub const U8_32_ASCENDING: [u8;32] = *b"\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0A\x0B\x0C\x0D\x0E\x0F\x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1A\x1B\x1C\x1D\x1E\x1F\x20";
     pub const U8_32_DESCENDING: [u8;32] = *b"\x20\x1F\x1E\x1D\x1C\x1B\x1A\x19\x18\x17\x16\x15\x14\x13\x12\x11\x10\x0F\x0E\x0D\x0C\x0B\x0A\x09\x08\x07\x06\x05\x04\x03\x02\x01";
     pub const U8_1_BYTE: [u8; 1] = [0x65];
@@ -109,10 +432,166 @@ mod tests {
     pub const S_QUICK_BROWN_FOX: &str = "The quick brown fox jumps over 13 lazy dogs.";
     pub const S_QUICK_BROWN_FOX_W_EOL: &str = "The quick brown fox jumps over 13 lazy dogs.\n";
     pub const S_QEOIC_SMOUAU_POSWUC: &str = "Qeoic-Smouau-Poswuc";
+    pub const KEY_32_BYTES: &[u8] = b"whats the Elvish word for friend";
+
+    #[test]
+    fn digest_all_reads_once_for_every_algorithm() {
+        let algorithms = [Crc32::new(), Md5::new(), Sha1::new(), Sha256::new(), Sha512::new()];
+        let mut read = std::io::Cursor::new(S_QUICK_BROWN_FOX);
+        let calculated = digest_all(&algorithms, &mut read).unwrap();
+        assert_eq!(calculated.len(), algorithms.len());
+        for (a, c) in algorithms.iter().zip(calculated) {
+            let expected = a.digest(&mut S_QUICK_BROWN_FOX.as_bytes()).unwrap();
+            assert_eq!(c.algorithm_name, a.name);
+            assert_eq!(c.bytes_read, S_QUICK_BROWN_FOX.len() as u64);
+            assert_eq!(c.digest, expected.digest);
+        }
+    }
+
+    #[test]
+    fn hasher_matches_digest_for_any_chunking() {
+        for algorithm in [Crc32::new(), Md5::new(), Sha256::new(), Shake128::new()] {
+            let expected = algorithm.digest(&mut S_QUICK_BROWN_FOX.as_bytes()).unwrap();
+            for chunk_size in [1, 3, 7, S_QUICK_BROWN_FOX.len()] {
+                let mut hasher = algorithm.hasher();
+                for chunk in S_QUICK_BROWN_FOX.as_bytes().chunks(chunk_size) {
+                    hasher.update(chunk);
+                }
+                let calculated = hasher.finalize();
+                assert_eq!(calculated.algorithm_name, algorithm.name);
+                assert_eq!(calculated.bytes_read, S_QUICK_BROWN_FOX.len() as u64);
+                assert_eq!(calculated.digest, expected.digest);
+            }
+        }
+    }
+
+    #[test]
+    fn calculated_digest_rendering_and_verify() {
+        let calculated = Md5::new().digest(&mut S_HI.as_bytes()).unwrap();
+        assert_eq!(calculated.to_hex(), "49f68a5c8493ec2c0bf489821c21fc3b");
+        assert_eq!(calculated.to_base64(), "SfaKXIST7CwL9ImCHCH8Ow==");
+        assert!(calculated.verify(&hex::decode("49f68a5c8493ec2c0bf489821c21fc3b").unwrap()));
+        assert!(!calculated.verify(&hex::decode("49f68a5c8493ec2c0bf489821c21fc3c").unwrap()));
+        assert!(!calculated.verify(&calculated.digest[..15]));
+        assert!(!calculated.verify(&[]));
+    }
+
+    #[test]
+    fn verify_crc_representations() {
+        let calculated = Cksum::new().digest(&mut "123456789".as_bytes()).unwrap();
+        assert_eq!(calculated.verify_crc("377A6011"), Some(CrcRepresentation::BigEndian));
+        assert_eq!(calculated.verify_crc("11607a37"), Some(CrcRepresentation::LittleEndian));
+        assert_eq!(calculated.verify_crc("930766865"), Some(CrcRepresentation::Decimal));
+        assert_eq!(calculated.verify_crc("930766866"), None);
+        assert_eq!(calculated.verify_crc("00000000377a6011"), None);
+    }
+
+    #[test]
+    fn strengths() {
+        assert_eq!(Crc32::new().strength, Strength::NonCryptographic);
+        assert_eq!(Md5::new().strength, Strength::Broken);
+        assert_eq!(Sha1::new().strength, Strength::Broken);
+        assert_eq!(Ripemd160::new().strength, Strength::Legacy);
+        assert_eq!(Sha256::new().strength, Strength::Strong);
+        assert_eq!(Sha256::new().with_key(b"Jefe").unwrap().strength, Strength::Strong);
+        assert_eq!(Algorithm::from_digest::<sha2::Sha256>("Custom-256").strength, Strength::Strong);
+    }
+
+    #[test]
+    fn keyed_algorithms() {
+        const RFC_4231_TEST_CASE_2: &str = "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+        assert!(Crc32::new().with_key(KEY_32_BYTES).is_none());
+        assert!(Shake128::new().with_key(KEY_32_BYTES).is_none());
+        assert!(Blake3::new().with_key(b"too short").is_none());
+        assert!(Algorithm::from_digest::<sha2::Sha256>("Custom-256").with_key(b"Jefe").is_none());
+        assert!(!Sha256::new().is_keyed());
+
+        let keyed = Sha256::new().with_key(b"Jefe").unwrap();
+        assert_eq!(keyed.name, "HMAC-SHA-256");
+        let mut hasher = keyed.hasher();
+        hasher.update(S_HI.as_bytes());
+        hasher.reset();
+        hasher.update(b"what do ya want for nothing?");
+        assert_eq!(hasher.finalize_reset().to_hex(), RFC_4231_TEST_CASE_2);
+        hasher.update(b"what do ya want for nothing?");
+        assert_eq!(hasher.finalize().to_hex(), RFC_4231_TEST_CASE_2);
+
+        let custom = Algorithm::from_block_digest::<sha2::Sha256>("Custom-256", "HMAC-Custom-256");
+        let calculated = custom
+            .with_key(b"Jefe")
+            .unwrap()
+            .digest(&mut "what do ya want for nothing?".as_bytes())
+            .unwrap();
+        assert_eq!(calculated.algorithm_name, "HMAC-Custom-256");
+        assert_eq!(calculated.to_hex(), RFC_4231_TEST_CASE_2);
+    }
+
+    #[test]
+    fn hasher_clone_and_reset() {
+        for algorithm in [Crc32::new(), Sha1::new()] {
+            let mut hasher = algorithm.hasher();
+            hasher.update(S_HI.as_bytes());
+            let prefix = hasher.clone();
+            hasher.update(&S_QUICK_BROWN_FOX.as_bytes()[S_HI.len()..]);
+            let expected_prefix = algorithm.digest(&mut S_HI.as_bytes()).unwrap();
+            let calculated_prefix = prefix.finalize();
+            assert_eq!(calculated_prefix.digest, expected_prefix.digest);
+            assert_eq!(calculated_prefix.bytes_read, S_HI.len() as u64);
+
+            hasher.reset();
+            assert_eq!(hasher.bytes_read(), 0);
+            hasher.update(S_HI.as_bytes());
+            assert_eq!(hasher.finalize_reset().digest, expected_prefix.digest);
+            assert_eq!(hasher.finalize().digest, algorithm.digest(&mut "".as_bytes()).unwrap().digest);
+        }
+    }
 }
 
+pub mod adler32;
+#[cfg(feature = "tokio")]
+pub mod asynchronous;
+pub mod blake2b512;
+pub mod blake2s256;
+pub mod blake3;
+mod checksum_digest;
+pub mod cksum;
+pub mod crc16;
 pub mod crc32;
+pub mod crc32c;
+pub mod crc64_ecma;
+pub mod crc64_nvme;
+pub mod crc64_xz;
+pub mod encoding;
+pub mod error;
+pub mod fnv1a_32;
+pub mod fnv1a_64;
+pub mod keccak256;
 pub mod md5;
+pub mod parallel;
+pub mod registry;
+pub mod ripemd160;
 pub mod sha1;
+pub mod sha224;
 pub mod sha256;
+pub mod sha384;
+pub mod sha3_224;
+pub mod sha3_256;
+pub mod sha3_384;
+pub mod sha3_512;
 pub mod sha512;
+pub mod sha512_224;
+pub mod sha512_256;
+pub mod shake128;
+pub mod shake256;
+pub mod sm3;
+pub mod sri;
+pub mod strength;
+pub mod streebog256;
+pub mod streebog512;
+pub mod sums;
+pub mod tiger;
+pub mod whirlpool;
+pub mod xxh32;
+pub mod xxh3_128;
+pub mod xxh3_64;
+pub mod xxh64;
@@ -1,129 +1,880 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT-0
 
 use clap::Parser;
-use digestify::crc32::Crc32;
-use digestify::md5::Md5;
-use digestify::sha1::Sha1;
-use digestify::sha256::Sha256;
-use digestify::sha512::Sha512;
-use digestify::{Algorithm, CalculatedDigest};
+use digestify::encoding::{decode_digest, is_decimal, CrcRepresentation, DecodedDigest};
+use digestify::parallel::{digest_file, digest_files};
+use digestify::sri::{parse_integrity, strongest_metadata};
+use digestify::sums::{
+    format_json_manifest, format_line, format_tag_line, parse_checksums, parse_tag_line, ChecksumEntry,
+};
+use digestify::registry::RegisteredAlgorithm;
+use digestify::{digest_all, Algorithm, CalculatedDigest, Error, Registry, Strength};
+use std::collections::HashSet;
+use std::ffi::OsString;
 use std::fs::File;
-use std::io::{Error, ErrorKind};
+use std::io::{BufReader, ErrorKind};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
+mod walk;
+use walk::{normalize, walk, WalkOptions};
 mod args;
-use args::DigestifyArgs;
+use args::{
+    known_algorithms, readable_files_name, Command, DigestifyArgs, GenerateArgs, KeyArgs, ManifestFormat, OutputFormat,
+    STDIN_FILE_NAME,
+};
+mod report;
+use report::{print_line, print_record, write_stdout, DigestResult, ExpectedDigest, FileRecord, Status, SummaryRecord};
 
-fn hex_len_to_size_description(hex_len: usize) -> String {
-    format!("{} hex chars / {} bits", hex_len, hex_len_to_bit_len(hex_len))
+fn to_input_description(file_name: &str) -> String {
+    match file_name {
+        STDIN_FILE_NAME => String::from("standard input"),
+        _ => format!("'{}'", file_name),
+    }
 }
 
-fn hex_len_to_bit_len(hex_len: usize) -> usize {
-    hex_len * 4
+fn to_size_description(encoded: &str, decoded: Option<&DecodedDigest>) -> String {
+    let decimal = format!("{} decimal digits", encoded.len());
+    match decoded {
+        Some(decoded) => {
+            let description = format!(
+                "{} {} chars / {} bits",
+                encoded.len(),
+                decoded.encoding.name(),
+                decoded.bit_len()
+            );
+            match is_decimal(encoded) {
+                true => format!("{} or {}", description, decimal),
+                false => description,
+            }
+        }
+        None => decimal,
+    }
 }
 
-fn to_algorithm_name_list(algorithms: &Vec<Algorithm>) -> String {
-    algorithms.into_iter().map(|a| a.name).collect::<Vec<&str>>().join(", ")
+/// Decodes a provided digest.  A decimal integer that isn't also valid in one of the `DigestEncoding`s can only be a
+/// CRC, so it's left undecoded.
+fn decode_provided_digest(provided: &str) -> Result<Option<DecodedDigest>, Error> {
+    match decode_digest(provided) {
+        Some(decoded) => Ok(Some(decoded)),
+        None if is_decimal(provided) => Ok(None),
+        None => Err(Error::InvalidEncoding(provided.to_string())),
+    }
 }
 
-fn find_candidates_based_on_digest_length<'a>(
-    supported_algorithms: &'a Vec<Algorithm<'a>>,
-    provided: &str,
-) -> Result<Vec<Algorithm<'a>>, Error> {
-    let candidate_algorithms: Vec<Algorithm> = supported_algorithms
-        .iter()
-        .filter(|a| hex_len_to_bit_len(provided.len()) == a.digest_bit_size.into())
-        .map(|a| *a)
-        .collect();
-    if candidate_algorithms.len() == 0 {
-        return Err(Error::new(
-            ErrorKind::Other,
-            format!(
-                "No supported algorithms for digest of size {}",
-                hex_len_to_size_description(provided.len())
-            ),
-        ));
+fn to_algorithm_name_list(algorithms: &[Algorithm]) -> String {
+    algorithms.iter().map(|a| a.name).collect::<Vec<&str>>().join(", ")
+}
+
+/// Finds the algorithms with a digest the size of the decoded digest, along with any CRCs wide enough to hold it when
+/// it's a decimal integer.
+fn find_candidates_based_on_digest_length(
+    registry: &Registry,
+    encoded: &str,
+    decoded: Option<&DecodedDigest>,
+) -> Result<Vec<Algorithm<'static>>, Error> {
+    let mut candidate_algorithms = decoded
+        .map(|d| registry.find_by_digest_bit_size(d.bit_len()))
+        .unwrap_or_default();
+    if is_decimal(encoded) {
+        for entry in registry.entries() {
+            let algorithm = entry.algorithm;
+            let size = usize::from(algorithm.digest_bit_size) / 8;
+            if algorithm.crc
+                && CrcRepresentation::Decimal.decode(encoded, size).is_some()
+                && !candidate_algorithms.iter().any(|c| c.name == algorithm.name)
+            {
+                candidate_algorithms.push(algorithm);
+            }
+        }
+    }
+    match (candidate_algorithms.is_empty(), decoded) {
+        (false, _) => Ok(candidate_algorithms),
+        (true, Some(decoded)) => Err(Error::UnsupportedDigestLength {
+            bits: decoded.bit_len(),
+        }),
+        (true, None) => Err(Error::InvalidEncoding(encoded.to_string())),
     }
-    Ok(candidate_algorithms)
 }
 
-fn calculate_digests(file_name: &str, candidate_algorithms: &Vec<Algorithm>) -> Result<Vec<CalculatedDigest>, Error> {
-    candidate_algorithms
+/// Uses the algorithm named by the digest itself, if any, otherwise the algorithms selected on the command line.  Only
+/// if neither names an algorithm are the candidates inferred from the digest's length.  A named extendable-output
+/// algorithm is sized to the digest, so it fails if it can't produce a digest that long.
+fn find_candidates(
+    registry: &Registry,
+    selected_algorithm_names: &[String],
+    provided: &ProvidedDigest,
+) -> Result<Vec<Algorithm<'static>>, Error> {
+    let names: Vec<&str> = match provided.algorithm_name.as_deref() {
+        Some(name) => vec![name],
+        None => selected_algorithm_names.iter().map(String::as_str).collect(),
+    };
+    if names.is_empty() {
+        return find_candidates_based_on_digest_length(registry, &provided.encoded, provided.decoded.as_ref());
+    }
+    let bit_len = provided.decoded.as_ref().map(DecodedDigest::bit_len);
+    names
         .into_iter()
-        .map(|a| calculate_file_digest(file_name, &a))
+        .map(|name| {
+            let algorithm = registry.lookup(name)?;
+            match bit_len {
+                Some(bits) if algorithm.extendable_output => u16::try_from(bits)
+                    .ok()
+                    .and_then(|b| algorithm.with_digest_bit_size(b))
+                    .ok_or(Error::UnsupportedDigestLength { bits }),
+                _ => Ok(algorithm),
+            }
+        })
         .collect()
 }
 
-struct DigestComparison<'a> {
-    provided: &'a str,
-    calculated: &'a CalculatedDigest,
+/// Calculates all of the candidate digests in a single pass.  Standard input is hashed as a stream, so unlike a file
+/// there's no length to check the bytes read against.
+fn calculate_digests(file_name: &str, candidate_algorithms: &[Algorithm]) -> Result<Vec<CalculatedDigest>, Error> {
+    if file_name == STDIN_FILE_NAME {
+        return digest_all(candidate_algorithms, &mut std::io::stdin().lock());
+    }
+    digest_file(Path::new(file_name), candidate_algorithms)
+}
+
+/// The expected and actual digests as they're printed, along with the CRC representation that matched, if any.
+struct DigestComparison {
+    expected: String,
+    actual: String,
+    representation: Option<CrcRepresentation>,
     matches: bool,
 }
 
-fn compare_digests<'a>(provided: &'a str, calculated: &'a CalculatedDigest) -> DigestComparison<'a> {
-    DigestComparison {
-        provided: provided,
-        calculated: &calculated,
-        matches: provided.eq_ignore_ascii_case(&calculated.digest),
+/// Compares a calculated digest to a provided one.  A CRC may be provided in any `CrcRepresentation`, so when it
+/// doesn't match it's printed in decimal if it looks like that's how it was provided.
+fn compare_digests(provided: &ProvidedDigest, calculated: &CalculatedDigest, crc: bool) -> DigestComparison {
+    if crc {
+        let representation = calculated.verify_crc(&provided.encoded);
+        let same_size = provided
+            .decoded
+            .as_ref()
+            .is_some_and(|d| d.bytes.len() == calculated.digest.len());
+        let shown = match representation {
+            Some(representation) => representation,
+            None if !same_size && is_decimal(&provided.encoded) => CrcRepresentation::Decimal,
+            None => CrcRepresentation::BigEndian,
+        };
+        return DigestComparison {
+            expected: provided.encoded.clone(),
+            actual: shown.encode(&calculated.digest),
+            representation,
+            matches: representation.is_some(),
+        };
+    }
+    match &provided.decoded {
+        Some(decoded) => DigestComparison {
+            expected: hex::encode(&decoded.bytes),
+            actual: calculated.to_hex(),
+            representation: None,
+            matches: calculated.verify(&decoded.bytes),
+        },
+        None => DigestComparison {
+            expected: provided.encoded.clone(),
+            actual: calculated.to_hex(),
+            representation: None,
+            matches: false,
+        },
+    }
+}
+
+/// Compares a calculated digest to every provided digest it was a candidate for.
+fn to_digest_result<'a>(
+    calculated: &'a CalculatedDigest,
+    candidate_algorithms: &[Algorithm],
+    provided_digests: &[&ProvidedDigest],
+) -> (DigestResult<'a>, Vec<DigestComparison>) {
+    let algorithm = candidate_algorithms
+        .iter()
+        .find(|a| a.name == calculated.algorithm_name);
+    let crc = algorithm.is_some_and(|a| a.crc);
+    let comparisons: Vec<DigestComparison> = provided_digests
+        .iter()
+        .map(|p| compare_digests(p, calculated, crc))
+        .collect();
+    let result = DigestResult {
+        calculated,
+        matches: comparisons.iter().any(|c| c.matches),
+        representation: comparisons.iter().find_map(|c| c.representation),
+        strength: algorithm.map_or(Strength::Strong, |a| a.strength),
+    };
+    (result, comparisons)
+}
+
+/// A digest provided on the command line or in a checksum file, along with the algorithm it was computed with when
+/// that's known.  It's only left undecoded when it's a decimal CRC.
+struct ProvidedDigest {
+    encoded: String,
+    algorithm_name: Option<String>,
+    decoded: Option<DecodedDigest>,
+    /// The file a BSD style line given on the command line says the digest is for.
+    file_name: Option<String>,
+}
+
+/// Parses a BSD style line, a Subresource Integrity string or a bare digest.  Only the strongest hashes of an SRI string
+/// are returned, as the SRI spec only considers those.
+fn parse_provided_digests(provided: &str) -> Result<Vec<ProvidedDigest>, Error> {
+    if let Some(entry) = parse_tag_line(provided) {
+        return Ok(vec![ProvidedDigest {
+            decoded: decode_provided_digest(&entry.digest)?,
+            encoded: entry.digest,
+            algorithm_name: entry.algorithm_name,
+            file_name: Some(entry.file_name),
+        }]);
+    }
+    let integrity = parse_integrity(provided);
+    if !integrity.is_empty() {
+        return Ok(strongest_metadata(&integrity)
+            .into_iter()
+            .map(|m| ProvidedDigest {
+                encoded: m.encoded_digest,
+                algorithm_name: Some(m.algorithm_name.to_string()),
+                decoded: Some(m.digest),
+                file_name: None,
+            })
+            .collect());
+    }
+    Ok(vec![ProvidedDigest {
+        decoded: decode_provided_digest(provided)?,
+        encoded: provided.to_string(),
+        algorithm_name: None,
+        file_name: None,
+    }])
+}
+
+fn to_expected_digest(provided: &ProvidedDigest) -> ExpectedDigest<'_> {
+    ExpectedDigest {
+        digest: &provided.encoded,
+        encoding: provided.decoded.as_ref().map_or("decimal", |d| d.encoding.name()),
+        bits: provided.decoded.as_ref().map(DecodedDigest::bit_len),
+        algorithm_name: provided.algorithm_name.as_deref(),
     }
 }
 
-fn calculate_file_digest<'a>(file_name: &str, algorithm: &'a Algorithm) -> Result<CalculatedDigest, Error> {
-    let mut file = File::open(file_name)?;
-    let metadata = file.metadata()?;
-    let d = algorithm.digest(&mut file)?;
-    if metadata.len() != d.bytes_read.into() {
-        let msg = format!(
-            "Wasn't able to read full file length of {} bytes.  Only read {}.",
-            metadata.len(),
-            d.bytes_read
+/// Names each matched algorithm along with its strength, e.g. `MD5 (broken)`.
+fn to_strength_list(results: &[DigestResult]) -> String {
+    results
+        .iter()
+        .filter(|r| r.matches)
+        .map(|r| format!("{} ({})", r.calculated.algorithm_name, r.strength.name()))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Results pass when the strongest algorithm that matched is at least `min_strength`.
+fn to_status(results: &[DigestResult], min_strength: Strength) -> Status {
+    match results.iter().filter(|r| r.matches).map(|r| r.strength).max() {
+        None => Status::Fail,
+        Some(strength) if strength < min_strength => Status::Weak,
+        Some(_) => Status::Pass,
+    }
+}
+
+fn to_outcome(status: Status, min_strength: Strength) -> Result<(), Error> {
+    match status {
+        Status::Pass => Ok(()),
+        Status::Weak => Err(Error::TooWeak { min_strength }),
+        _ => Err(Error::Mismatch),
+    }
+}
+
+fn verify_file(
+    file_name: &str,
+    provided: &str,
+    registry: &Registry,
+    selected_algorithm_names: &[String],
+    min_strength: Strength,
+    output_format: OutputFormat,
+) -> Result<(), Error> {
+    let start = Instant::now();
+    if file_name != STDIN_FILE_NAME {
+        readable_files_name(file_name)?;
+    }
+    let provided_digests = parse_provided_digests(provided)?;
+    warn_if_for_other_file(&provided_digests, file_name);
+    let candidates_per_digest = provided_digests
+        .iter()
+        .map(|p| find_candidates(registry, selected_algorithm_names, p))
+        .collect::<Result<Vec<Vec<Algorithm>>, Error>>()?;
+    let mut candidate_algorithms: Vec<Algorithm> = Vec::new();
+    for a in candidates_per_digest.iter().flatten() {
+        if !candidate_algorithms.iter().any(|c| c.name == a.name) {
+            candidate_algorithms.push(*a);
+        }
+    }
+
+    let mut size_descriptions: Vec<String> = Vec::new();
+    for p in &provided_digests {
+        let description = to_size_description(&p.encoded, p.decoded.as_ref());
+        if !size_descriptions.contains(&description) {
+            size_descriptions.push(description);
+        }
+    }
+
+    if output_format == OutputFormat::Text {
+        print_line!(
+            "\nVerifying {} against provided digest of size {}.  Candidate digest(s): {}.",
+            to_input_description(file_name),
+            size_descriptions.join(" or "),
+            to_algorithm_name_list(&candidate_algorithms)
+        )?;
+    }
+
+    let calculated = calculate_digests(file_name, &candidate_algorithms)?;
+    let mut results = Vec::new();
+    for calculated in &calculated {
+        let provided_for_algorithm: Vec<&ProvidedDigest> = provided_digests
+            .iter()
+            .zip(&candidates_per_digest)
+            .filter(|(_, candidates)| candidates.iter().any(|c| c.name == calculated.algorithm_name))
+            .map(|(p, _)| p)
+            .collect();
+        let (result, comparisons) = to_digest_result(calculated, &candidate_algorithms, &provided_for_algorithm);
+
+        if output_format == OutputFormat::Text {
+            let match_string = if result.matches { "PASS" } else { "FAIL" };
+            let notes = result.notes();
+            match notes.is_empty() {
+                true => print_line!("\n {}: {}    ", calculated.algorithm_name, match_string)?,
+                false => print_line!(
+                    "\n {}: {} ({})    ",
+                    calculated.algorithm_name,
+                    match_string,
+                    notes.join(", ")
+                )?,
+            }
+            for comparison in comparisons {
+                print_line!("\tExpected={}\n\t  Actual={}", comparison.expected, comparison.actual)?;
+            }
+        }
+        results.push(result);
+    }
+    let status = to_status(&results, min_strength);
+    let weak_only = results.iter().filter(|r| r.matches).all(|r| r.strength.is_weak());
+
+    match output_format {
+        OutputFormat::Json => print_record(
+            &FileRecord {
+                expected: provided_digests.iter().map(to_expected_digest).collect(),
+                candidates: candidate_algorithms.iter().map(|a| a.name).collect(),
+                digests: results,
+                elapsed: start.elapsed(),
+                ..FileRecord::new(file_name, status)
+            }
+            .to_json(),
+        )?,
+        OutputFormat::Text if status == Status::Pass => {
+            eprintln!("\nPASS: Provided digest matches the content.");
+            if weak_only {
+                eprintln!(
+                    "\nWarning: The content only matches weak digests, so it may have been tampered with: {}.",
+                    to_strength_list(&results)
+                );
+            }
+        }
+        OutputFormat::Text if status == Status::Weak => eprintln!(
+            "\nFAIL: Provided digest only matches {}, which is weaker than the required strength ({}).",
+            to_strength_list(&results),
+            min_strength.name()
+        ),
+        OutputFormat::Text => {
+            eprintln!("\nFAIL: Provided digest doesn't match any of the candidate digest results.")
+        }
+    }
+    to_outcome(status, min_strength)
+}
+
+/// Warns if a BSD style line names a different file than the one being verified, as it may have been copied from the
+/// wrong line of a checksum file.  Standard input has no name to compare.
+fn warn_if_for_other_file(provided_digests: &[ProvidedDigest], file_name: &str) {
+    let Some(named) = provided_digests.iter().find_map(|p| p.file_name.as_deref()) else {
+        return;
+    };
+    if file_name != STDIN_FILE_NAME && normalize(Path::new(named)) != normalize(Path::new(file_name)) {
+        eprintln!(
+            "\nWarning: The provided digest is for '{}', not '{}'.",
+            named, file_name
         );
-        return Err(Error::new(ErrorKind::Other, msg));
     }
-    Ok(d)
 }
 
-fn main() -> Result<(), Error> {
-    let args = DigestifyArgs::parse();
+fn to_provided_digest(entry: &ChecksumEntry) -> Result<ProvidedDigest, Error> {
+    Ok(ProvidedDigest {
+        decoded: decode_provided_digest(&entry.digest)?,
+        encoded: entry.digest.clone(),
+        algorithm_name: entry.algorithm_name.clone(),
+        file_name: None,
+    })
+}
 
-    let file_name = args.file_name;
-    let provided = args.digest;
+/// The provided digest and candidate algorithms for a checksum file entry.
+fn prepare_entry(
+    entry: &ChecksumEntry,
+    registry: &Registry,
+    selected_algorithm_names: &[String],
+) -> Result<(ProvidedDigest, Vec<Algorithm<'static>>), Error> {
+    let provided = to_provided_digest(entry)?;
+    let candidate_algorithms = find_candidates(registry, selected_algorithm_names, &provided)?;
+    Ok((provided, candidate_algorithms))
+}
 
-    let supported_algorithms: Vec<Algorithm> =
-        vec![Crc32::new(), Md5::new(), Sha1::new(), Sha256::new(), Sha512::new()];
+/// What became of one checksum file entry.  Entries that couldn't be hashed are known before any hashing starts.
+enum EntryStatus {
+    Missing,
+    Invalid(Error),
+    Hashed(ProvidedDigest, Vec<Algorithm<'static>>),
+}
 
-    let candidate_algorithms = find_candidates_based_on_digest_length(&supported_algorithms, &provided)?;
+#[derive(Default)]
+struct CheckCounts {
+    passed: usize,
+    failures: usize,
+    missing: usize,
+    /// Files that only matched digests weaker than `--min-strength` allows
+    weak: usize,
+    /// Files that passed, but only matched weak digests
+    weak_matches: usize,
+    /// The first error that kept a file from being verified, which decides the exit code
+    error: Option<Error>,
+}
 
-    println!(
-        "\nVerifying '{}' against provided digest of size {}.  Candidate digest(s): {}.",
-        file_name,
-        hex_len_to_size_description(provided.len()),
-        to_algorithm_name_list(&candidate_algorithms)
-    );
+fn print_entry_result(
+    entry: &ChecksumEntry,
+    status: EntryStatus,
+    calculated: Option<(Result<Vec<CalculatedDigest>, Error>, Duration)>,
+    min_strength: Strength,
+    output_format: OutputFormat,
+    counts: &mut CheckCounts,
+) -> std::io::Result<()> {
+    let elapsed = calculated.as_ref().map(|(_, elapsed)| *elapsed).unwrap_or_default();
+    let mut record = FileRecord::new(&entry.file_name, Status::Error);
+    record.elapsed = elapsed;
+    let provided = to_provided_digest(entry).ok();
+    record.expected.extend(provided.as_ref().map(to_expected_digest));
+    let calculated = match calculated {
+        Some((Ok(calculated), _)) => calculated,
+        Some((Err(e), _)) => {
+            record.error = Some(e.to_string());
+            counts.error.get_or_insert(e);
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    match status {
+        EntryStatus::Missing => record.status = Status::Missing,
+        EntryStatus::Invalid(e) => {
+            record.error = Some(e.to_string());
+            counts.error.get_or_insert(e);
+        }
+        EntryStatus::Hashed(provided, candidates) => {
+            record.candidates = candidates.iter().map(|a| a.name).collect();
+            record.digests = calculated
+                .iter()
+                .map(|c| to_digest_result(c, &candidates, &[&provided]).0)
+                .collect();
+            if record.error.is_none() {
+                record.status = to_status(&record.digests, min_strength);
+            }
+        }
+    }
 
-    let mut match_count = 0;
-    for calculated in calculate_digests(&file_name, &candidate_algorithms)? {
-        let comparison = compare_digests(&provided, &calculated);
+    match record.status {
+        Status::Pass => {
+            counts.passed += 1;
+            if record.digests.iter().filter(|r| r.matches).all(|r| r.strength.is_weak()) {
+                counts.weak_matches += 1;
+            }
+        }
+        Status::Weak => counts.weak += 1,
+        Status::Missing => counts.missing += 1,
+        _ => counts.failures += 1,
+    }
+    if output_format == OutputFormat::Json {
+        return print_record(&record.to_json());
+    }
+    match record.status {
+        Status::Pass | Status::Weak => {
+            let matched = record.digests.iter().find(|r| r.matches).unwrap();
+            let notes = std::iter::once(matched.calculated.algorithm_name.as_str())
+                .chain(matched.notes())
+                .collect::<Vec<&str>>();
+            print_line!(" {}: {} ({})", entry.file_name, record.status.name().to_uppercase(), notes.join(", "))
+        }
+        Status::Fail => print_line!(" {}: FAIL", entry.file_name),
+        Status::Missing => print_line!(" {}: MISSING", entry.file_name),
+        _ => print_line!(" {}: ERROR ({})", entry.file_name, record.error.unwrap_or_default()),
+    }
+}
+
+/// Verifies the files listed in a checksum file.  If `paths` are given, only listed files at or under them are verified
+/// and any other files found there are reported as extra.  Up to `jobs` files are hashed at once.  If a file couldn't
+/// be verified at all, the error for the first is returned rather than a mismatch.
+#[allow(clippy::too_many_arguments)]
+fn check_sums_file(
+    sums_file_name: &str,
+    paths: &[String],
+    walk_options: &WalkOptions,
+    jobs: NonZeroUsize,
+    registry: &Registry,
+    selected_algorithm_names: &[String],
+    min_strength: Strength,
+    output_format: OutputFormat,
+) -> Result<(), Error> {
+    let start = Instant::now();
+    let checksums = parse_checksums(&mut BufReader::new(File::open(readable_files_name(sums_file_name)?)?))?;
+    let entries = &checksums.entries;
 
-        let match_string = match comparison.matches {
-            true => {
-                match_count += 1;
-                "PASS"
+    let (entries, extra_files): (Vec<&ChecksumEntry>, Vec<PathBuf>) = match paths.is_empty() {
+        true => (entries.iter().collect(), Vec::new()),
+        false => {
+            let roots: Vec<PathBuf> = paths.iter().map(|p| normalize(Path::new(p))).collect();
+            let mut listed: HashSet<PathBuf> = entries.iter().map(|e| normalize(Path::new(&e.file_name))).collect();
+            listed.insert(normalize(Path::new(sums_file_name)));
+            let extra_files = walk(paths, walk_options)?
+                .into_iter()
+                .filter(|f| !listed.contains(f))
+                .collect();
+            let covered = entries
+                .iter()
+                .filter(|e| walk_options.covers(&roots, &normalize(Path::new(&e.file_name))))
+                .collect();
+            (covered, extra_files)
+        }
+    };
+
+    if output_format == OutputFormat::Text {
+        print_line!("\nChecking {} file(s) listed in '{}'.\n", entries.len(), sums_file_name)?;
+    }
+
+    let mut statuses = Vec::new();
+    let mut files = Vec::new();
+    let mut file_entries = Vec::new();
+    for (i, entry) in entries.iter().enumerate() {
+        if !Path::new(&entry.file_name).exists() {
+            statuses.push(EntryStatus::Missing);
+            continue;
+        }
+        match prepare_entry(entry, registry, selected_algorithm_names) {
+            Ok((provided, candidate_algorithms)) => {
+                files.push((entry.file_name.as_str(), candidate_algorithms.clone()));
+                statuses.push(EntryStatus::Hashed(provided, candidate_algorithms));
+                file_entries.push(i);
             }
-            false => "FAIL",
-        };
+            Err(e) => statuses.push(EntryStatus::Invalid(e)),
+        }
+    }
+
+    // Results arrive in order, so any entries before each one that weren't hashed are printed first.  The first error
+    // writing them out is kept until hashing is done.
+    let mut counts = CheckCounts::default();
+    let mut statuses = statuses.into_iter().enumerate();
+    let mut write_error = None;
+    digest_files(&files, jobs, |file_index, calculated, elapsed| {
+        let entry_index = file_entries[file_index];
+        let mut calculated = Some((calculated, elapsed));
+        for (i, status) in statuses.by_ref() {
+            let hashed = i == entry_index;
+            let calculated = if hashed { calculated.take() } else { None };
+            let printed = print_entry_result(entries[i], status, calculated, min_strength, output_format, &mut counts);
+            if let Err(e) = printed {
+                write_error.get_or_insert(e);
+            }
+            if hashed {
+                break;
+            }
+        }
+    });
+    if let Some(e) = write_error {
+        return Err(e.into());
+    }
+    for (i, status) in statuses {
+        print_entry_result(entries[i], status, None, min_strength, output_format, &mut counts)?;
+    }
+    for extra_file in &extra_files {
+        let extra_file = extra_file.to_string_lossy();
+        match output_format {
+            OutputFormat::Json => print_record(&FileRecord::new(&extra_file, Status::Extra).to_json())?,
+            OutputFormat::Text => print_line!(" {}: EXTRA", extra_file)?,
+        }
+    }
+
+    let summary = SummaryRecord {
+        sums_file_name,
+        listed: entries.len(),
+        passed: counts.passed,
+        failed: counts.failures,
+        missing: counts.missing,
+        extra: extra_files.len(),
+        weak: counts.weak,
+        malformed: checksums.malformed_lines.len(),
+        elapsed: start.elapsed(),
+    };
+    let error = counts.error.or_else(|| {
+        let line_number = *checksums.malformed_lines.first()?;
+        Some(Error::MalformedChecksumLine { line_number })
+    });
+    if output_format == OutputFormat::Json {
+        print_record(&summary.to_json())?;
+        return error.map_or_else(|| to_outcome(summary.status(), min_strength), Err);
+    }
 
-        println!("\n {}: {}    ", comparison.calculated.algorithm_name, match_string);
-        println!(
-            "\tExpected={}\n\t  Actual={}",
-            comparison.provided.to_ascii_lowercase(),
-            comparison.calculated.digest
+    if counts.failures > 0 {
+        eprintln!(
+            "\nFAIL: {} of {} file(s) could not be verified against the provided digests.",
+            counts.failures,
+            entries.len()
         );
     }
-    if match_count == 0 {
-        eprintln!("\nFAIL: Provided digest doesn't match any of the candidate digest results.");
-        std::process::exit(2);
-    } else {
-        eprintln!("\nPASS: Provided digest matches the content.");
+    if counts.missing > 0 {
+        eprintln!("\nFAIL: {} listed file(s) are missing.", counts.missing);
+    }
+    if !extra_files.is_empty() {
+        eprintln!(
+            "\nFAIL: {} file(s) are not listed in '{}'.",
+            extra_files.len(),
+            sums_file_name
+        );
+    }
+    if summary.malformed > 0 {
+        eprintln!(
+            "\nFAIL: {} line(s) in '{}' are not properly formatted and were skipped.",
+            summary.malformed, sums_file_name
+        );
+    }
+    if counts.weak > 0 {
+        eprintln!(
+            "\nFAIL: {} file(s) only match digests weaker than the required strength ({}).",
+            counts.weak,
+            min_strength.name()
+        );
+    }
+    if summary.status() != Status::Pass {
+        return error.map_or_else(|| to_outcome(summary.status(), min_strength), Err);
+    }
+    eprintln!("\nPASS: All {} file(s) match the provided digests.", entries.len());
+    if counts.weak_matches > 0 {
+        eprintln!(
+            "\nWarning: {} file(s) only match weak digests, so they may have been tampered with.",
+            counts.weak_matches
+        );
     }
     Ok(())
 }
+
+fn expand_file_patterns(patterns: &[String]) -> Result<Vec<String>, Error> {
+    let mut file_names = Vec::new();
+    for pattern in patterns {
+        if Path::new(pattern).is_file() {
+            file_names.push(pattern.clone());
+            continue;
+        }
+        let paths = glob::glob(pattern)
+            .map_err(|e| Error::InvalidArgument(format!("Invalid pattern '{}': {}", pattern, e)))?;
+        let matched_count = file_names.len();
+        for path in paths {
+            let path = path.map_err(std::io::Error::from)?;
+            if path.is_file() {
+                file_names.push(path.to_string_lossy().into_owned());
+            }
+        }
+        if file_names.len() == matched_count {
+            return Err(std::io::Error::new(ErrorKind::NotFound, format!("No files match '{}'", pattern)).into());
+        }
+    }
+    Ok(file_names)
+}
+
+fn generate(args: &GenerateArgs, registry: &Registry) -> Result<(), Error> {
+    let algorithms = args
+        .algorithms
+        .iter()
+        .map(|name| registry.lookup(name))
+        .collect::<Result<Vec<Algorithm>, Error>>()?;
+
+    let file_names = expand_file_patterns(&args.files)?;
+    let requests: Vec<(&str, Vec<Algorithm>)> = file_names.iter().map(|f| (f.as_str(), algorithms.clone())).collect();
+    let mut files = Vec::new();
+    let mut failure = None;
+    digest_files(&requests, args.jobs, |i, calculated, _| match calculated {
+        Ok(calculated) => files.push((file_names[i].clone(), calculated)),
+        Err(e) => {
+            failure.get_or_insert((i, e));
+        }
+    });
+    if let Some((i, e)) = failure {
+        eprintln!("Couldn't compute the digests of '{}'.", file_names[i]);
+        return Err(e);
+    }
+
+    let manifest = match args.format {
+        ManifestFormat::Gnu => algorithms
+            .iter()
+            .flat_map(|a| {
+                files.iter().map(move |(file_name, calculated)| {
+                    let c = calculated.iter().find(|c| c.algorithm_name == a.name).unwrap();
+                    format_line(&c.to_hex(), file_name, false) + "\n"
+                })
+            })
+            .collect(),
+        ManifestFormat::Bsd => files
+            .iter()
+            .flat_map(|(file_name, calculated)| {
+                calculated
+                    .iter()
+                    .map(move |c| format_tag_line(&c.algorithm_name, file_name, &c.to_hex()) + "\n")
+            })
+            .collect(),
+        ManifestFormat::Json => format_json_manifest(&files) + "\n",
+    };
+
+    match &args.output {
+        Some(output) => std::fs::write(output, manifest)?,
+        None => write_stdout(manifest.as_bytes())?,
+    }
+    Ok(())
+}
+
+/// The process exit code for each kind of failure.  A mismatch keeps the exit code of 2 it has always had.
+fn exit_code(e: &Error) -> i32 {
+    match e {
+        Error::Mismatch => 2,
+        Error::Io(_) => 3,
+        Error::ShortRead { .. } => 4,
+        Error::InvalidEncoding(_) => 5,
+        Error::MalformedChecksumLine { .. } => 6,
+        Error::UnsupportedDigestLength { .. } => 7,
+        Error::UnknownAlgorithm { .. } => 8,
+        Error::TooWeak { .. } => 9,
+        Error::InvalidArgument(_) => 1,
+    }
+}
+
+/// Reads the key given with `--hmac-key-file` or `--hmac-key-env`.  It's used for the rest of the run, so it's leaked
+/// to live as long as the algorithms in the registry.
+fn read_key(args: &KeyArgs) -> Result<Option<&'static [u8]>, Error> {
+    let key = match (&args.hmac_key_file, &args.hmac_key_env) {
+        (Some(file_name), _) => std::fs::read(readable_files_name(file_name)?)?,
+        (None, Some(var)) => {
+            let value = std::env::var_os(var)
+                .ok_or_else(|| Error::InvalidArgument(format!("Environment variable '{}' isn't set", var)))?;
+            to_key_bytes(value, var)?
+        }
+        (None, None) => return Ok(None),
+    };
+    if key.is_empty() {
+        return Err(Error::InvalidArgument(String::from("The key is empty")));
+    }
+    Ok(Some(Box::leak(key.into_boxed_slice())))
+}
+
+/// The bytes of a key read from an environment variable, which needn't be valid UTF-8.
+#[cfg(unix)]
+fn to_key_bytes(value: OsString, _var: &str) -> Result<Vec<u8>, Error> {
+    use std::os::unix::ffi::OsStringExt;
+    Ok(value.into_vec())
+}
+
+/// The bytes of a key read from an environment variable.  Elsewhere, variables are only bytes if they're valid Unicode.
+#[cfg(not(unix))]
+fn to_key_bytes(value: OsString, var: &str) -> Result<Vec<u8>, Error> {
+    value
+        .into_string()
+        .map(String::into_bytes)
+        .map_err(|_| Error::InvalidArgument(format!("Environment variable '{}' isn't valid Unicode", var)))
+}
+
+/// The registry of algorithms to use, keyed if a key was given.
+fn to_registry(args: &KeyArgs) -> Result<Registry, Error> {
+    Ok(match read_key(args)? {
+        Some(key) => to_keyed_registry(&Registry::new(), key),
+        None => Registry::new(),
+    })
+}
+
+/// A registry of the algorithms that can be keyed with `key`, so that candidates are chosen and reported the same way
+/// as unkeyed digests.  Each can still be found by the names of the unkeyed algorithm, e.g. `-a SHA-256` selects
+/// HMAC-SHA-256.
+fn to_keyed_registry(registry: &Registry, key: &'static [u8]) -> Registry {
+    let mut keyed = Registry::empty();
+    for entry in registry.entries() {
+        if let Some(algorithm) = entry.algorithm.with_key(key) {
+            let mut keyed_entry = RegisteredAlgorithm::new(algorithm);
+            keyed_entry.aliases = std::iter::once(entry.algorithm.name)
+                .chain(entry.aliases.iter().copied())
+                .collect();
+            keyed.register(keyed_entry);
+        }
+    }
+    keyed
+}
+
+/// Usage errors exit with 1 rather than clap's usual 2, since 2 has always meant the content didn't match.
+fn exit_on_usage_error(e: clap::Error) -> ! {
+    let _ = e.print();
+    std::process::exit(if e.use_stderr() { 1 } else { 0 });
+}
+
+fn run(args: DigestifyArgs) -> Result<(), Error> {
+    if let Some(Command::Generate(generate_args)) = args.command {
+        return generate(&generate_args, &to_registry(&generate_args.key)?);
+    }
+
+    let registry = to_registry(&args.key)?;
+
+    known_algorithms(&registry, &args.algorithms)?;
+    let min_strength = args.min_strength()?;
+    match &args.check {
+        Some(sums_file_name) => check_sums_file(
+            sums_file_name,
+            &args.paths,
+            &args.walk_options()?,
+            args.jobs,
+            &registry,
+            &args.algorithms,
+            min_strength,
+            args.format,
+        ),
+        None => {
+            let (file_name, provided) = args.file_and_digest().unwrap_or_else(|e| exit_on_usage_error(e));
+            let result = verify_file(
+                &file_name,
+                &provided,
+                &registry,
+                &args.algorithms,
+                min_strength,
+                args.format,
+            );
+            match result {
+                Err(ref e) if args.format == OutputFormat::Json && !is_reported(e) => {
+                    let record = FileRecord {
+                        error: Some(e.to_string()),
+                        ..FileRecord::new(&file_name, Status::Error)
+                    };
+                    print_record(&record.to_json())?;
+                }
+                _ => {}
+            }
+            result
+        }
+    }
+}
+
+/// Whether the PASS/FAIL report has already said why verification failed.
+fn is_reported(e: &Error) -> bool {
+    matches!(e, Error::Mismatch | Error::TooWeak { .. })
+}
+
+fn main() {
+    let args = DigestifyArgs::try_parse().unwrap_or_else(|e| exit_on_usage_error(e));
+    if let Err(e) = run(args) {
+        if !is_reported(&e) {
+            eprintln!("Error: {}", e);
+        }
+        std::process::exit(exit_code(&e));
+    }
+}
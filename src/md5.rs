@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT-0
 
-use crate::{calculated_digest, Algorithm};
+use crate::{hmac, new_hasher, Algorithm, Strength};
 
 pub struct Md5 {}
 
@@ -9,7 +9,11 @@ impl Md5 {
         Algorithm {
             digest_bit_size: 128,
             name: "MD5",
-            digest_fn: calculated_digest::<md5::Md5>,
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Broken,
+            hasher_fn: new_hasher::<md5::Md5>,
+            keying: hmac::<md5::Md5>("HMAC-MD5"),
         }
     }
 }
@@ -17,7 +21,7 @@ impl Md5 {
 #[cfg(test)]
 mod tests {
     use super::Md5;
-    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
     test_algorithm!(Md5, empty, U8_EMPTY, "d41d8cd98f00b204e9800998ecf8427e");
     test_algorithm!(Md5, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "70bc8f4b72a86921468bf8e8441dce51");
     test_algorithm!(Md5, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "70a4f0549692bbb3bf9b54fb97b2c898");
@@ -32,4 +36,5 @@ mod tests {
     test_algorithm_s!(Md5, quick_brown_fox, S_QUICK_BROWN_FOX, "6363fe744f74ee8f280958ab2f185dde");
     test_algorithm_s!(Md5, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "26b694e5659c7caa44f040907ba82612");
     test_algorithm_s!(Md5, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "03a54624ecc2b05289ccac57b9c9ec58");
+    test_keyed_algorithm_s!(Md5, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "c3bb9da58033ee8ebaf95c9305ef8e1b");
 }
@@ -0,0 +1,218 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::sync::{mpsc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{digest_all, Algorithm, CalculatedDigest, Error};
+
+/// Calculates a digest of the file at `path` for each of the `algorithms` in a single pass.  Fails if fewer bytes
+/// were read than the file's length.
+pub fn digest_file(path: &Path, algorithms: &[Algorithm]) -> Result<Vec<CalculatedDigest>, Error> {
+    let mut file = File::open(path)?;
+    let metadata = file.metadata()?;
+    let calculated = digest_all(algorithms, &mut file)?;
+    if let Some(d) = calculated.iter().find(|d| d.bytes_read != metadata.len()) {
+        return Err(Error::ShortRead {
+            expected: metadata.len(),
+            actual: d.bytes_read,
+        });
+    }
+    Ok(calculated)
+}
+
+/// Calculates the digests of many files on up to `jobs` threads.  `report` is called on the calling thread with the
+/// index and results of each file, along with how long it took to hash, always in the same order as `files`.
+///
+/// At most `jobs` files are open at once and each is streamed through a fixed-size buffer.  Files are only started
+/// while fewer than `2 * jobs` results are waiting on an earlier, slower file, so memory use depends on `jobs` rather
+/// than on how many files there are or how large they are.
+pub fn digest_files<P: AsRef<Path> + Sync>(
+    files: &[(P, Vec<Algorithm>)],
+    jobs: NonZeroUsize,
+    mut report: impl FnMut(usize, Result<Vec<CalculatedDigest>, Error>, Duration),
+) {
+    map_ordered(
+        files,
+        jobs,
+        |(path, algorithms)| {
+            let start = Instant::now();
+            let calculated = digest_file(path.as_ref(), algorithms);
+            (calculated, start.elapsed())
+        },
+        |i, (calculated, elapsed)| report(i, calculated, elapsed),
+    );
+}
+
+struct Progress {
+    started: usize,
+    reported: usize,
+    /// Set if results stopped being received, e.g. because `report` panicked, so that no more items are started.
+    cancelled: bool,
+}
+
+/// Cancels the remaining items when dropped, waking any workers waiting for a free slot.
+struct CancelOnDrop<'a> {
+    progress: &'a Mutex<Progress>,
+    reported_changed: &'a Condvar,
+}
+
+impl Drop for CancelOnDrop<'_> {
+    fn drop(&mut self) {
+        // A worker that panicked while holding the lock has nothing left to wake.
+        if let Ok(mut p) = self.progress.lock() {
+            p.cancelled = true;
+        }
+        self.reported_changed.notify_all();
+    }
+}
+
+fn map_ordered<T: Sync, R: Send>(
+    items: &[T],
+    jobs: NonZeroUsize,
+    f: impl Fn(&T) -> R + Sync,
+    mut report: impl FnMut(usize, R),
+) {
+    let window = jobs.get().saturating_mul(2);
+    let progress = Mutex::new(Progress {
+        started: 0,
+        reported: 0,
+        cancelled: false,
+    });
+    let reported_changed = Condvar::new();
+
+    // Waits for a free slot in the window, then claims the next item.  Returns `None` once every item is claimed or
+    // the rest have been cancelled.
+    let next_item = || {
+        let mut p = progress.lock().unwrap();
+        while !p.cancelled && p.started < items.len() && p.started >= p.reported + window {
+            p = reported_changed.wait(p).unwrap();
+        }
+        if p.cancelled || p.started == items.len() {
+            return None;
+        }
+        p.started += 1;
+        Some(p.started - 1)
+    };
+
+    std::thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+        for _ in 0..jobs.get().min(items.len()) {
+            let sender = sender.clone();
+            let (f, next_item) = (&f, &next_item);
+            scope.spawn(move || {
+                while let Some(i) = next_item() {
+                    if sender.send((i, f(&items[i]))).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        let _cancel = CancelOnDrop {
+            progress: &progress,
+            reported_changed: &reported_changed,
+        };
+        let mut waiting = BTreeMap::new();
+        let mut reported = 0;
+        for (i, result) in receiver {
+            waiting.insert(i, result);
+            while let Some(result) = waiting.remove(&reported) {
+                report(reported, result);
+                reported += 1;
+            }
+            progress.lock().unwrap().reported = reported;
+            reported_changed.notify_all();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{digest_file, digest_files, map_ordered};
+    use crate::{md5::Md5, sha256::Sha256};
+    use std::num::NonZeroUsize;
+    use std::panic::AssertUnwindSafe;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn results_are_reported_in_order() {
+        let items: Vec<u64> = (0..50).collect();
+        let mut reported = Vec::new();
+        map_ordered(
+            &items,
+            NonZeroUsize::new(8).unwrap(),
+            |i| {
+                // Makes earlier items finish last.
+                std::thread::sleep(Duration::from_millis(50 - i));
+                i * 2
+            },
+            |i, r| reported.push((i, r)),
+        );
+        assert_eq!(reported, items.iter().map(|i| (*i as usize, i * 2)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn started_items_are_bounded_by_window() {
+        let items: Vec<usize> = (0..40).collect();
+        let max_ahead = AtomicUsize::new(0);
+        let reported = AtomicUsize::new(0);
+        map_ordered(
+            &items,
+            NonZeroUsize::new(2).unwrap(),
+            |i| {
+                max_ahead.fetch_max(i - reported.load(Ordering::SeqCst), Ordering::SeqCst);
+                // The first item is slow, so the others pile up behind it.
+                std::thread::sleep(Duration::from_millis(if *i == 0 { 100 } else { 1 }));
+            },
+            |i, _| reported.store(i + 1, Ordering::SeqCst),
+        );
+        assert_eq!(reported.load(Ordering::SeqCst), items.len());
+        assert!(max_ahead.load(Ordering::SeqCst) < 4);
+    }
+
+    #[test]
+    fn panicking_report_stops_workers() {
+        let items: Vec<usize> = (0..40).collect();
+        let started = AtomicUsize::new(0);
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            map_ordered(
+                &items,
+                NonZeroUsize::new(2).unwrap(),
+                |_| {
+                    started.fetch_add(1, Ordering::SeqCst);
+                },
+                |_, _| panic!("Can't report"),
+            )
+        }));
+        assert!(result.is_err());
+        assert!(started.load(Ordering::SeqCst) < items.len());
+    }
+
+    #[test]
+    fn digest_files_matches_digest_file() {
+        let algorithms = vec![Md5::new(), Sha256::new()];
+        let files: Vec<(PathBuf, _)> = ["Cargo.toml", "LICENSE-APACHE", "LICENSE-MIT", "README.md"]
+            .iter()
+            .map(|f| (PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(f), algorithms.clone()))
+            .collect();
+        let mut reported = 0;
+        digest_files(&files, NonZeroUsize::new(3).unwrap(), |i, calculated, _| {
+            let calculated = calculated.unwrap();
+            let expected = digest_file(&files[i].0, &algorithms).unwrap();
+            assert_eq!(i, reported);
+            assert_eq!(
+                calculated.iter().map(|c| &c.digest).collect::<Vec<_>>(),
+                expected.iter().map(|c| &c.digest).collect::<Vec<_>>()
+            );
+            reported += 1;
+        });
+        assert_eq!(reported, files.len());
+    }
+}
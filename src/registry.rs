@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::adler32::Adler32;
+use crate::blake2b512::Blake2b512;
+use crate::blake2s256::Blake2s256;
+use crate::blake3::Blake3;
+use crate::cksum::Cksum;
+use crate::crc16::Crc16;
+use crate::crc32::Crc32;
+use crate::crc32c::Crc32c;
+use crate::crc64_ecma::Crc64Ecma;
+use crate::crc64_nvme::Crc64Nvme;
+use crate::crc64_xz::Crc64Xz;
+use crate::fnv1a_32::Fnv1a32;
+use crate::fnv1a_64::Fnv1a64;
+use crate::keccak256::Keccak256;
+use crate::md5::Md5;
+use crate::ripemd160::Ripemd160;
+use crate::sha1::Sha1;
+use crate::sha224::Sha224;
+use crate::sha256::Sha256;
+use crate::sha384::Sha384;
+use crate::sha3_224::Sha3_224;
+use crate::sha3_256::Sha3_256;
+use crate::sha3_384::Sha3_384;
+use crate::sha3_512::Sha3_512;
+use crate::sha512::Sha512;
+use crate::sha512_224::Sha512_224;
+use crate::sha512_256::Sha512_256;
+use crate::shake128::Shake128;
+use crate::shake256::Shake256;
+use crate::sm3::Sm3;
+use crate::streebog256::Streebog256;
+use crate::streebog512::Streebog512;
+use crate::tiger::Tiger;
+use crate::whirlpool::Whirlpool;
+use crate::xxh32::Xxh32;
+use crate::xxh3_128::Xxh3_128;
+use crate::xxh3_64::Xxh3_64;
+use crate::xxh64::Xxh64;
+use crate::{Algorithm, Error};
+
+/// An algorithm along with the other names and identifiers it's known by.
+#[derive(Clone)]
+pub struct RegisteredAlgorithm {
+    pub algorithm: Algorithm<'static>,
+    /// Names other than `algorithm.name` that find this algorithm, e.g. `sha2-256` or the `BLAKE2b` tag written by
+    /// `b2sum --tag`.
+    pub aliases: Vec<&'static str>,
+    /// The ASN.1 object identifier in dotted form, e.g. `2.16.840.1.101.3.4.2.1` for SHA-256.
+    pub oid: Option<&'static str>,
+    /// The code identifying the algorithm in a multihash, e.g. `0x12` for SHA-256.
+    pub multihash_code: Option<u64>,
+}
+
+impl RegisteredAlgorithm {
+    pub fn new(algorithm: Algorithm<'static>) -> RegisteredAlgorithm {
+        RegisteredAlgorithm {
+            algorithm,
+            aliases: Vec::new(),
+            oid: None,
+            multihash_code: None,
+        }
+    }
+
+    fn is_named(&self, name: &str) -> bool {
+        let normalized = normalize_name(name);
+        normalize_name(self.algorithm.name) == normalized || self.aliases.iter().any(|a| normalize_name(a) == normalized)
+    }
+}
+
+/// Names are compared ignoring case, dashes and underscores, so `sha256`, `SHA_256` and `SHA-256` are all the same.
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+type BuiltIn = (fn() -> Algorithm<'static>, &'static [&'static str], Option<&'static str>, Option<u64>);
+
+/// The built-in algorithms, in ascending order of digest size.
+const BUILT_INS: [BuiltIn; 38] = [
+    (Crc16::new, &["CRC-16/ARC", "CRC-16/IBM"], None, None),
+    (Crc32::new, &[], None, Some(0x0132)),
+    (Crc32c::new, &["CRC-32/ISCSI", "CRC-32/Castagnoli"], None, None),
+    (Cksum::new, &["POSIX-cksum"], None, None),
+    (Xxh32::new, &[], None, None),
+    (Adler32::new, &[], None, None),
+    (Fnv1a32::new, &[], None, None),
+    (Crc64Ecma::new, &["CRC-64", "CRC-64/ECMA"], None, Some(0x0164)),
+    (Crc64Xz::new, &["CRC-64/GO-ECMA"], None, None),
+    (Crc64Nvme::new, &[], None, None),
+    (Xxh64::new, &[], None, None),
+    (Xxh3_64::new, &["XXH3"], None, None),
+    (Fnv1a64::new, &[], None, None),
+    (Xxh3_128::new, &["XXH128"], None, None),
+    (Md5::new, &[], Some("1.2.840.113549.2.5"), Some(0xd5)),
+    (Sha1::new, &[], Some("1.3.14.3.2.26"), Some(0x11)),
+    (Ripemd160::new, &["RMD160"], Some("1.3.36.3.2.1"), Some(0x1053)),
+    (Tiger::new, &["Tiger192"], Some("1.3.6.1.4.1.11591.12.2"), None),
+    (Sha224::new, &["sha2-224"], Some("2.16.840.1.101.3.4.2.4"), Some(0x1013)),
+    (Sha512_224::new, &["sha2-512-224", "sha512-224"], Some("2.16.840.1.101.3.4.2.5"), Some(0x1014)),
+    (Sha3_224::new, &[], Some("2.16.840.1.101.3.4.2.7"), Some(0x17)),
+    (Sha256::new, &["sha2-256"], Some("2.16.840.1.101.3.4.2.1"), Some(0x12)),
+    (Sha512_256::new, &["sha2-512-256", "sha512-256"], Some("2.16.840.1.101.3.4.2.6"), Some(0x1015)),
+    (Sha3_256::new, &[], Some("2.16.840.1.101.3.4.2.8"), Some(0x16)),
+    (Keccak256::new, &[], None, Some(0x1b)),
+    (Blake2s256::new, &["BLAKE2s"], Some("1.3.6.1.4.1.1722.12.2.2.8"), Some(0xb260)),
+    (Blake3::new, &[], None, Some(0x1e)),
+    (Sm3::new, &["SM3-256"], Some("1.2.156.10197.1.401"), Some(0x534)),
+    (Streebog256::new, &["GOST-2012-256", "md_gost12_256"], Some("1.2.643.7.1.1.2.2"), None),
+    (Sha384::new, &["sha2-384"], Some("2.16.840.1.101.3.4.2.2"), Some(0x20)),
+    (Sha3_384::new, &[], Some("2.16.840.1.101.3.4.2.9"), Some(0x15)),
+    (Sha512::new, &["sha2-512"], Some("2.16.840.1.101.3.4.2.3"), Some(0x13)),
+    (Sha3_512::new, &[], Some("2.16.840.1.101.3.4.2.10"), Some(0x14)),
+    (Blake2b512::new, &["BLAKE2b"], Some("1.3.6.1.4.1.1722.12.2.1.16"), Some(0xb240)),
+    (Streebog512::new, &["GOST-2012-512", "md_gost12_512"], Some("1.2.643.7.1.1.2.3"), None),
+    (Whirlpool::new, &[], Some("1.0.10118.3.0.55"), None),
+    (Shake128::new, &[], Some("2.16.840.1.101.3.4.2.11"), Some(0x18)),
+    (Shake256::new, &[], Some("2.16.840.1.101.3.4.2.12"), Some(0x19)),
+];
+
+/// The algorithms available for computing and verifying digests, and the names and identifiers they can be looked up
+/// by.  `Registry::new()` has every built-in algorithm, and more can be added with `register`.
+#[derive(Clone)]
+pub struct Registry {
+    entries: Vec<RegisteredAlgorithm>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Registry::new()
+    }
+}
+
+impl Registry {
+    /// A registry of all of the built-in algorithms.
+    pub fn new() -> Registry {
+        Registry {
+            entries: BUILT_INS
+                .iter()
+                .map(|(new, aliases, oid, multihash_code)| RegisteredAlgorithm {
+                    algorithm: new(),
+                    aliases: aliases.to_vec(),
+                    oid: *oid,
+                    multihash_code: *multihash_code,
+                })
+                .collect(),
+        }
+    }
+
+    /// A registry with no algorithms, for embedders that want to choose exactly which are available.
+    pub fn empty() -> Registry {
+        Registry { entries: Vec::new() }
+    }
+
+    /// Adds an algorithm, replacing any already registered under the same name.
+    pub fn register(&mut self, entry: RegisteredAlgorithm) {
+        match self.entries.iter_mut().find(|e| e.algorithm.name == entry.algorithm.name) {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    pub fn entries(&self) -> &[RegisteredAlgorithm] {
+        &self.entries
+    }
+
+    /// Every registered algorithm, in the order registered.
+    pub fn algorithms(&self) -> Vec<Algorithm<'static>> {
+        self.entries.iter().map(|e| e.algorithm).collect()
+    }
+
+    /// Looks up an algorithm by its name or one of its aliases.
+    pub fn find(&self, name: &str) -> Option<&RegisteredAlgorithm> {
+        self.entries.iter().find(|e| e.is_named(name))
+    }
+
+    /// Like `find`, but fails with an error listing the registered algorithms.
+    pub fn lookup(&self, name: &str) -> Result<Algorithm<'static>, Error> {
+        self.find(name)
+            .map(|e| e.algorithm)
+            .ok_or_else(|| Error::UnknownAlgorithm {
+                name: name.to_string(),
+                supported: self.entries.iter().map(|e| e.algorithm.name.to_string()).collect(),
+            })
+    }
+
+    pub fn find_by_oid(&self, oid: &str) -> Option<&RegisteredAlgorithm> {
+        self.entries.iter().find(|e| e.oid == Some(oid))
+    }
+
+    pub fn find_by_multihash_code(&self, code: u64) -> Option<&RegisteredAlgorithm> {
+        self.entries.iter().find(|e| e.multihash_code == Some(code))
+    }
+
+    /// Every algorithm that can produce a digest of `digest_bit_size` bits.  Extendable-output algorithms are
+    /// returned sized to match.
+    pub fn find_by_digest_bit_size(&self, digest_bit_size: usize) -> Vec<Algorithm<'static>> {
+        self.entries
+            .iter()
+            .filter_map(|e| match e.algorithm.extendable_output {
+                true => u16::try_from(digest_bit_size)
+                    .ok()
+                    .and_then(|b| e.algorithm.with_digest_bit_size(b)),
+                false => Some(e.algorithm).filter(|a| digest_bit_size == usize::from(a.digest_bit_size)),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RegisteredAlgorithm, Registry};
+    use crate::{Algorithm, Error};
+
+    #[test]
+    fn find_by_name_and_alias() {
+        let registry = Registry::new();
+        for name in ["SHA-256", "sha256", "sha2-256", "SHA_256"] {
+            assert_eq!(registry.find(name).unwrap().algorithm.name, "SHA-256", "{}", name);
+        }
+        assert_eq!(registry.find("sha512-256").unwrap().algorithm.name, "SHA-512/256");
+        assert_eq!(registry.find("blake2b").unwrap().algorithm.name, "BLAKE2b-512");
+        assert_eq!(registry.find("ripemd160").unwrap().algorithm.name, "RIPEMD-160");
+        assert_eq!(registry.find("md_gost12_256").unwrap().algorithm.name, "Streebog-256");
+        assert!(registry.find("sha257").is_none());
+    }
+
+    #[test]
+    fn lookup_unknown_algorithm() {
+        let registry = Registry::new();
+        match registry.lookup("foo") {
+            Err(Error::UnknownAlgorithm { name, supported }) => {
+                assert_eq!(name, "foo");
+                assert_eq!(supported.len(), registry.entries().len());
+            }
+            _ => panic!("Expected an unknown algorithm error"),
+        }
+    }
+
+    #[test]
+    fn find_by_identifiers() {
+        let registry = Registry::new();
+        assert_eq!(registry.find_by_oid("2.16.840.1.101.3.4.2.3").unwrap().algorithm.name, "SHA-512");
+        assert_eq!(registry.find_by_multihash_code(0x1e).unwrap().algorithm.name, "BLAKE3");
+        assert_eq!(registry.find_by_oid("1.2.156.10197.1.401").unwrap().algorithm.name, "SM3");
+        assert!(registry.find_by_oid("1.2.3").is_none());
+    }
+
+    #[test]
+    fn find_by_digest_bit_size() {
+        let names = |bits| {
+            Registry::new()
+                .find_by_digest_bit_size(bits)
+                .iter()
+                .map(|a| a.name)
+                .collect::<Vec<&str>>()
+        };
+        assert_eq!(names(160), ["SHA-1", "RIPEMD-160"]);
+        assert_eq!(names(192), ["Tiger"]);
+        assert_eq!(names(224), ["SHA-224", "SHA-512/224", "SHA3-224", "SHAKE128", "SHAKE256"]);
+        assert_eq!(
+            names(256),
+            [
+                "SHA-256",
+                "SHA-512/256",
+                "SHA3-256",
+                "Keccak-256",
+                "BLAKE2s-256",
+                "BLAKE3",
+                "SM3",
+                "Streebog-256",
+                "SHAKE128",
+                "SHAKE256"
+            ]
+        );
+        assert_eq!(names(32), ["CRC-32", "CRC-32C", "cksum", "XXH32", "Adler-32", "FNV-1a-32"]);
+        assert_eq!(names(64), ["CRC-64/ECMA-182", "CRC-64/XZ", "CRC-64/NVME", "XXH64", "XXH3-64", "FNV-1a-64"]);
+        assert!(names(8).is_empty());
+        assert!(names(12).is_empty());
+        assert!(names(40).is_empty());
+    }
+
+    #[test]
+    fn register_custom_algorithm() {
+        let mut registry = Registry::empty();
+        let mut entry = RegisteredAlgorithm::new(Algorithm::from_digest::<sha2::Sha256>("Custom-256"));
+        entry.aliases.push("c256");
+        registry.register(entry);
+        let algorithm = registry.lookup("C256").unwrap();
+        assert_eq!(algorithm.digest_bit_size, 256);
+        assert_eq!(
+            algorithm.digest(&mut "hi".as_bytes()).unwrap().to_hex(),
+            "8f434346648f6b96df89dda901c5176b10a6d83961dd3c1ac88b59b2dc327aa4"
+        );
+    }
+
+    #[test]
+    fn register_replaces_same_name() {
+        let mut registry = Registry::new();
+        let count = registry.entries().len();
+        registry.register(RegisteredAlgorithm::new(Algorithm::from_digest::<sha2::Sha256>("MD5")));
+        assert_eq!(registry.entries().len(), count);
+        assert_eq!(registry.lookup("md5").unwrap().digest_bit_size, 256);
+    }
+}
@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use digestify::encoding::CrcRepresentation;
+use digestify::{CalculatedDigest, Strength};
+use serde_json::{json, Value};
+use std::io::{self, ErrorKind, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// The content only matches digests weaker than `--min-strength` allows
+    Weak,
+    Error,
+    Missing,
+    Extra,
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Weak => "weak",
+            Status::Error => "error",
+            Status::Missing => "missing",
+            Status::Extra => "extra",
+        }
+    }
+}
+
+/// A digest the file is expected to have, as it was provided.
+pub struct ExpectedDigest<'a> {
+    pub digest: &'a str,
+    pub encoding: &'static str,
+    /// `None` for a decimal CRC, whose size isn't known until it's compared.
+    pub bits: Option<usize>,
+    pub algorithm_name: Option<&'a str>,
+}
+
+/// A calculated digest and whether it matched, along with the representation a CRC matched in.
+pub struct DigestResult<'a> {
+    pub calculated: &'a CalculatedDigest,
+    pub matches: bool,
+    pub representation: Option<CrcRepresentation>,
+    /// The algorithm's strength.  Weak algorithms are flagged, as a match doesn't rule out tampering.
+    pub strength: Strength,
+}
+
+impl DigestResult<'_> {
+    /// What's shown in brackets after the result in text output: the representation a CRC matched in and the
+    /// algorithm's strength when it's weak.
+    pub fn notes(&self) -> Vec<&'static str> {
+        self.representation
+            .map(|r| r.name())
+            .into_iter()
+            .chain(Some(self.strength.name()).filter(|_| self.strength.is_weak()))
+            .collect()
+    }
+}
+
+/// The outcome of verifying one file.
+pub struct FileRecord<'a> {
+    pub file_name: &'a str,
+    pub status: Status,
+    pub expected: Vec<ExpectedDigest<'a>>,
+    pub candidates: Vec<&'a str>,
+    pub digests: Vec<DigestResult<'a>>,
+    pub error: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl<'a> FileRecord<'a> {
+    pub fn new(file_name: &'a str, status: Status) -> FileRecord<'a> {
+        FileRecord {
+            file_name,
+            status,
+            expected: Vec::new(),
+            candidates: Vec::new(),
+            digests: Vec::new(),
+            error: None,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "type": "file",
+            "file": self.file_name,
+            "status": self.status.name(),
+            "expected": self.expected.iter().map(|e| json!({
+                "digest": e.digest,
+                "encoding": e.encoding,
+                "bits": e.bits,
+                "algorithm": e.algorithm_name,
+            })).collect::<Vec<Value>>(),
+            "candidates": self.candidates,
+            "digests": self.digests.iter().map(|r| json!({
+                "algorithm": r.calculated.algorithm_name,
+                "bytes_read": r.calculated.bytes_read,
+                "digest": r.calculated.to_hex(),
+                "matches": r.matches,
+                "representation": r.representation.map(|representation| representation.name()),
+                "cryptographic": r.strength != Strength::NonCryptographic,
+                "strength": r.strength.name(),
+            })).collect::<Vec<Value>>(),
+            "error": self.error,
+            "elapsed_seconds": self.elapsed.as_secs_f64(),
+        })
+    }
+}
+
+/// Totals for a `--check` run, written after every file record.
+pub struct SummaryRecord<'a> {
+    pub sums_file_name: &'a str,
+    pub listed: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub missing: usize,
+    pub extra: usize,
+    /// Files that only matched digests weaker than `--min-strength` allows.
+    pub weak: usize,
+    /// Lines of the checksum file that couldn't be parsed and were skipped.
+    pub malformed: usize,
+    pub elapsed: Duration,
+}
+
+impl SummaryRecord<'_> {
+    /// Any failure to match, or a malformed line, outweighs files only matching weak digests.
+    pub fn status(&self) -> Status {
+        match (self.failed + self.missing + self.extra + self.malformed, self.weak) {
+            (0, 0) => Status::Pass,
+            (0, _) => Status::Weak,
+            _ => Status::Fail,
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "type": "summary",
+            "sums_file": self.sums_file_name,
+            "status": self.status().name(),
+            "listed": self.listed,
+            "passed": self.passed,
+            "failed": self.failed,
+            "missing": self.missing,
+            "extra": self.extra,
+            "weak": self.weak,
+            "malformed": self.malformed,
+            "elapsed_seconds": self.elapsed.as_secs_f64(),
+        })
+    }
+}
+
+/// Set once writing to standard output has found that the reader has gone away.
+static STDOUT_CLOSED: AtomicBool = AtomicBool::new(false);
+
+/// Like `println!`, but returns an error rather than panicking if standard output can't be written to.
+macro_rules! print_line {
+    ($($arg:tt)*) => {
+        $crate::report::write_stdout(format!("{}\n", format_args!($($arg)*)).as_bytes())
+    };
+}
+
+pub(crate) use print_line;
+
+/// Writes to standard output for `print_line!`.  Once the reader has gone away, e.g. when the output is piped into
+/// `head`, anything else written is dropped so that the run still finishes with the exit code for its results.
+pub fn write_stdout(bytes: &[u8]) -> io::Result<()> {
+    if STDOUT_CLOSED.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+    match std::io::stdout().lock().write_all(bytes) {
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => {
+            STDOUT_CLOSED.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+/// Writes a record for `--format json`.  Each record is a JSON object on a single line whose `type` is `file` or
+/// `summary`, so that output for many files is newline-delimited JSON.
+pub fn print_record(record: &Value) -> io::Result<()> {
+    print_line!("{}", record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_record_fields() {
+        let calculated = CalculatedDigest {
+            bytes_read: 2,
+            algorithm_name: "CRC-32".to_string(),
+            digest: vec![0xd8, 0x93, 0x2a, 0xac],
+        };
+        let record = FileRecord {
+            expected: vec![ExpectedDigest {
+                digest: "3633523372",
+                encoding: "decimal",
+                bits: None,
+                algorithm_name: None,
+            }],
+            candidates: vec!["CRC-32"],
+            digests: vec![DigestResult {
+                calculated: &calculated,
+                matches: true,
+                representation: Some(CrcRepresentation::Decimal),
+                strength: Strength::NonCryptographic,
+            }],
+            elapsed: Duration::from_millis(250),
+            ..FileRecord::new("hi.txt", Status::Pass)
+        };
+        assert_eq!(
+            record.to_json(),
+            json!({
+                "type": "file",
+                "file": "hi.txt",
+                "status": "pass",
+                "expected": [{"digest": "3633523372", "encoding": "decimal", "bits": null, "algorithm": null}],
+                "candidates": ["CRC-32"],
+                "digests": [{
+                    "algorithm": "CRC-32",
+                    "bytes_read": 2,
+                    "digest": "d8932aac",
+                    "matches": true,
+                    "representation": "decimal",
+                    "cryptographic": false,
+                    "strength": "non-cryptographic",
+                }],
+                "error": null,
+                "elapsed_seconds": 0.25,
+            })
+        );
+    }
+
+    #[test]
+    fn error_file_record_fields() {
+        let record = FileRecord {
+            error: Some("File 'a' cannot be found".to_string()),
+            ..FileRecord::new("a", Status::Error)
+        };
+        assert_eq!(
+            record.to_json(),
+            json!({
+                "type": "file",
+                "file": "a",
+                "status": "error",
+                "expected": [],
+                "candidates": [],
+                "digests": [],
+                "error": "File 'a' cannot be found",
+                "elapsed_seconds": 0.0,
+            })
+        );
+    }
+
+    #[test]
+    fn summary_record_fields() {
+        let summary = SummaryRecord {
+            sums_file_name: "SHA256SUMS",
+            listed: 5,
+            passed: 2,
+            failed: 1,
+            missing: 1,
+            extra: 3,
+            weak: 1,
+            malformed: 2,
+            elapsed: Duration::from_millis(1500),
+        };
+        assert_eq!(
+            summary.to_json(),
+            json!({
+                "type": "summary",
+                "sums_file": "SHA256SUMS",
+                "status": "fail",
+                "listed": 5,
+                "passed": 2,
+                "failed": 1,
+                "missing": 1,
+                "extra": 3,
+                "weak": 1,
+                "malformed": 2,
+                "elapsed_seconds": 1.5,
+            })
+        );
+    }
+
+    #[test]
+    fn summary_status() {
+        let summary = |failed, weak| SummaryRecord {
+            sums_file_name: "SUMS",
+            listed: 2,
+            passed: 2 - failed - weak,
+            failed,
+            missing: 0,
+            extra: 0,
+            weak,
+            malformed: 0,
+            elapsed: Duration::ZERO,
+        };
+        assert_eq!(summary(0, 0).status().name(), "pass");
+        assert_eq!(summary(0, 1).status().name(), "weak");
+        assert_eq!(summary(1, 1).status().name(), "fail");
+        assert_eq!(
+            SummaryRecord {
+                malformed: 1,
+                ..summary(0, 1)
+            }
+            .status()
+            .name(),
+            "fail"
+        );
+    }
+}
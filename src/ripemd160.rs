@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+pub struct Ripemd160 {}
+
+impl Ripemd160 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 160,
+            name: "RIPEMD-160",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Legacy,
+            hasher_fn: new_hasher::<ripemd::Ripemd160>,
+            keying: hmac::<ripemd::Ripemd160>("HMAC-RIPEMD-160"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ripemd160;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Ripemd160, empty, U8_EMPTY, "9c1185a5c5e9fc54612808977ee8f548b2258d31");
+    test_algorithm!(Ripemd160, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "d1a70126ff7a149ca6f9b638db084480440ff842");
+    test_algorithm!(Ripemd160, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "29bce157d9fa12f0be97f24c103774ba47782afb");
+    test_algorithm!(Ripemd160, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "1cf4e77f5966e13e109703cd8a0df7ceda7f3dc3");
+    test_algorithm!(Ripemd160, one_byte, U8_1_BYTE, "0d42741db982eb2a3f615f46e41114bb64a1a476");
+    test_algorithm!(Ripemd160, two_bytes, U8_2_BYTES, "dab74ee28e29a120b038e8ee41dfee5826fff298");
+    test_algorithm!(Ripemd160, three_bytes, U8_3_BYTES, "7c50f6575304463994cc6df1700c4a98505379f6");
+    test_algorithm!(Ripemd160, four_bytes, U8_4_BYTES, "e9e5626456ab20f69baa19cb01e09c5231fba843");
+    test_algorithm!(Ripemd160, ascending, U8_32_ASCENDING, "af6cc066e3745a4c73c5a7f6e01b85e726d60bf3");
+    test_algorithm!(Ripemd160, descending, U8_32_DESCENDING, "913aa7a9d609ac4af85a8cc017cde8d186ba1938");
+    test_algorithm_s!(Ripemd160, hi, S_HI, "242485ab6bfd3502bcb3442ea2e211687b8e4d89");
+    test_algorithm_s!(Ripemd160, quick_brown_fox, S_QUICK_BROWN_FOX, "52c57d1d28248bac97b6d4688f79121dd274dc06");
+    test_algorithm_s!(Ripemd160, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "f78dd34520bf1e22109543db701a326baeba88a0");
+    test_algorithm_s!(Ripemd160, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "636b4ee4e41d98b9d8a5264f6423cb5da56a4a3f");
+    test_algorithm_s!(Ripemd160, abc, "abc", "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc");
+    test_keyed_algorithm_s!(Ripemd160, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "993be60ede8f44307d9b4b148d3b98609d4452d1");
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT-0
 
-use crate::{Algorithm, calculated_digest};
+use crate::{hmac, new_hasher, Algorithm, Strength};
 
 pub struct Sha1 {}
 
@@ -9,7 +9,11 @@ impl Sha1 {
         Algorithm {
             digest_bit_size: 160,
             name: "SHA-1",
-            digest_fn: calculated_digest::<sha1::Sha1>,
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Broken,
+            hasher_fn: new_hasher::<sha1::Sha1>,
+            keying: hmac::<sha1::Sha1>("HMAC-SHA-1"),
         }
     }
 }
@@ -17,7 +21,7 @@ impl Sha1 {
 #[cfg(test)]
 mod tests {
     use super::Sha1;
-    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
     test_algorithm!(Sha1, empty, U8_EMPTY, "da39a3ee5e6b4b0d3255bfef95601890afd80709");
     test_algorithm!(Sha1, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "de8a847bff8c343d69b853a215e6ee775ef2ef96");
     test_algorithm!(Sha1, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "9e1da9d4154cf5a448938421ccb7891adaa4d2fe");
@@ -32,4 +36,5 @@ mod tests {
     test_algorithm_s!(Sha1, quick_brown_fox, S_QUICK_BROWN_FOX, "cb4a8948faa586ccc1694d80e25941a5227d8b9e");
     test_algorithm_s!(Sha1, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "743c817468028042ef80b0d32242ec7171c67525");
     test_algorithm_s!(Sha1, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "a25ddb163a3bd72e2392c25f19388ec1d0351716");
+    test_keyed_algorithm_s!(Sha1, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "8fc80b43462d2aa961e3e05147c76e2cc26ddb38");
 }
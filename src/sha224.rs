@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+pub struct Sha224 {}
+
+impl Sha224 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 224,
+            name: "SHA-224",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha2::Sha224>,
+            keying: hmac::<sha2::Sha224>("HMAC-SHA-224"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha224;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Sha224, empty, U8_EMPTY, "d14a028c2a3a2bc9476102bb288234c415a2b01f828ea62ac5b3e42f");
+    test_algorithm!(Sha224, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "b338c76bcffa1a0b3ead8de58dfbff47b63ab1150e10d8f17f2bafdf");
+    test_algorithm!(Sha224, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "f8b881778b469a2f851144579277f66585d80fe9a2aee247443b6743");
+    test_algorithm!(Sha224, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "3458e8904cb8b41bf7eac31822b82e91363f4eb91901766758a8f3a3");
+    test_algorithm!(Sha224, one_byte, U8_1_BYTE, "d5070e2f67ededca022f81f2941900606b16f3196b2268e856295f59");
+    test_algorithm!(Sha224, two_bytes, U8_2_BYTES, "4d262b6bfc7a7e3605aeaa7cabfd68638584252cf6aa5d81c446723f");
+    test_algorithm!(Sha224, three_bytes, U8_3_BYTES, "d7ceadaf4b8570e017b4de7223ba31c2948eb3d8dc62a4f0823c182b");
+    test_algorithm!(Sha224, four_bytes, U8_4_BYTES, "7a4134258361a7fcda265b7a8963870350f6e2ad688533d6215e6c32");
+    test_algorithm!(Sha224, ascending, U8_32_ASCENDING, "835fed2dbe56c7d6adab737be5e45506b0fa9e5e6ff4e99cbcc814c5");
+    test_algorithm!(Sha224, descending, U8_32_DESCENDING, "a07c2ab18b910fdbd3e2c6f0072b288e4ac27699550784f47e5f6267");
+    test_algorithm_s!(Sha224, hi, S_HI, "1a15bca3e4ed3ab82367c6c434f86c41040bdac577fa1c2de62c3be0");
+    test_algorithm_s!(Sha224, quick_brown_fox, S_QUICK_BROWN_FOX, "098d10d3f5c263e16daca488e02793246fa811afe9a66524abe4aacf");
+    test_algorithm_s!(Sha224, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "67439444754fa3261fb5ab32ff63a8a66421bed9877a2385519f4530");
+    test_algorithm_s!(Sha224, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "ecf0bcd80e4b54a04666dfee6448cf51e28a0eaee3dcb5de64190ff8");
+    test_keyed_algorithm_s!(Sha224, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "6ceca4caf232436b2a5f64ee92416c5015733d3f8f5246c7c6f06600");
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT-0
 
-use crate::{calculated_digest, Algorithm};
+use crate::{hmac, new_hasher, Algorithm, Strength};
 
 pub struct Sha256 {}
 
@@ -9,7 +9,11 @@ impl Sha256 {
         Algorithm {
             digest_bit_size: 256,
             name: "SHA-256",
-            digest_fn: calculated_digest::<sha2::Sha256>,
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha2::Sha256>,
+            keying: hmac::<sha2::Sha256>("HMAC-SHA-256"),
         }
     }
 }
@@ -17,7 +21,7 @@ impl Sha256 {
 #[cfg(test)]
 mod tests {   
     use super::Sha256;
-    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
     test_algorithm!(Sha256, empty, U8_EMPTY, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
     test_algorithm!(Sha256, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "66687aadf862bd776c8fc18b8e9f8e20089714856ee233b3902a591d0d5f2925");
     test_algorithm!(Sha256, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "9b68d49bb092f71292ad76ab8fb8750d710aae5af70e43b8ec0a901d048c0030");
@@ -32,4 +36,6 @@ mod tests {
     test_algorithm_s!(Sha256, quick_brown_fox, S_QUICK_BROWN_FOX, "531fd7e9f8f6d4b3836684a94b1b39e0966842dcf0ba251f8f8d774dad6e5ed9");
     test_algorithm_s!(Sha256, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "f74fe80e1e1a01568a2695bdedafe64a11fe54bfb1729d888f9f66e6a8b873b8");
     test_algorithm_s!(Sha256, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "acc178e4a10e25274b6b05c47444e89528ea82199c70db9b1fe4c7e45df9013d");
+    test_keyed_algorithm_s!(Sha256, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "24c705d65780247d3c93a2fdbbe60540e39ea2741d62d323f166e1665482c7c9");
+    test_keyed_algorithm_s!(Sha256, keyed_rfc_4231_test_case_2, b"Jefe", "what do ya want for nothing?", "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
 }
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+pub struct Sha384 {}
+
+impl Sha384 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 384,
+            name: "SHA-384",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha2::Sha384>,
+            keying: hmac::<sha2::Sha384>("HMAC-SHA-384"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha384;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Sha384, empty, U8_EMPTY, "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da274edebfe76f65fbd51ad2f14898b95b");
+    test_algorithm!(Sha384, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "a38fff4ba26c15e4ac9cde8c03103ac89080fd47545fde9446c8f192729eab7bd03a4d5c3187f75fe2a71b0ee50a4a40");
+    test_algorithm!(Sha384, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "e2136c73506737fe0df94243df3ef2285aca729d7777900caa5b2596c9a7c3067031d7bbd2b5b231fe355398a73c0312");
+    test_algorithm!(Sha384, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "53d8721ba229b1daed0a0b9ff5feeb40f0a2abf211fd3263a1f680498eea447cd2970ad5ca0ae5a3515a73e3e72ed411");
+    test_algorithm!(Sha384, one_byte, U8_1_BYTE, "8d182905e535537a32cc0c475403d1fe78ee541a40d61e0b306d7541ed8dbb63d550dab383d0fca0e23448af99bffe10");
+    test_algorithm!(Sha384, two_bytes, U8_2_BYTES, "a16fa60289fd4eb16539ad2a3e1ef185a511a76a8cabf1ab75047c4f1e7823813d926266e46fafb1d031dfc2af36aae1");
+    test_algorithm!(Sha384, three_bytes, U8_3_BYTES, "78ed9018419b4b78d97d385521d87549e58dbf078dab10a84bfb90c957c8536f9c67c7a42cbd99f1f463ac33b7054f81");
+    test_algorithm!(Sha384, four_bytes, U8_4_BYTES, "3c7d977387f146f938b3df120f5379d95bb6a5248c142df8822fec9e032c2ce6e9e7bec0f9329392b9dddf0f332512e3");
+    test_algorithm!(Sha384, ascending, U8_32_ASCENDING, "79a017feef31127390e00e7bb6cb0e5be29e71fc6e079b2f28004b53068fa336d922a4404d8065339eebd0eefba39b7f");
+    test_algorithm!(Sha384, descending, U8_32_DESCENDING, "f7f7a98af8be697d22b11e1bfa22b129a802ec0b211cebbaa9573b290b3d0cb9287f7d4b5aacfa60ef52cd49420f710c");
+    test_algorithm_s!(Sha384, hi, S_HI, "0791006df8128477244f53d0fdce210db81f55757510e26acee35c18a6bceaa28dcdbbfd6dc041b9b4dc7b1b54e37f52");
+    test_algorithm_s!(Sha384, quick_brown_fox, S_QUICK_BROWN_FOX, "7854da8262c1255262b0b3513fcf5b52263d1fa9b2464a5bf822dfd049ceb8919b4af9b94d60360be72f41b329903695");
+    test_algorithm_s!(Sha384, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "132944906f699fed92980b8e064eca4c7f991516f3e39175f3911d840f4515230e1561b9b03c6fe5d5e5c3e647f26ea0");
+    test_algorithm_s!(Sha384, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "351dee86683215725b2c758125f9ee09f7a466260e7fa3fff0c0ee0942f397e7f7cb608932bb3bbb0ca5008d235eb9e6");
+    test_keyed_algorithm_s!(Sha384, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "715e50a989f15a568b645a693a643067e404014fac921ef830649bf783e309666d51e2a0f0714fd514112e8554f8b66f");
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+#[allow(non_camel_case_types)]
+pub struct Sha3_224 {}
+
+impl Sha3_224 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 224,
+            name: "SHA3-224",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha3::Sha3_224>,
+            keying: hmac::<sha3::Sha3_224>("HMAC-SHA3-224"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha3_224;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Sha3_224, empty, U8_EMPTY, "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7");
+    test_algorithm!(Sha3_224, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "73e087ae1271b2c5f68546c93ab42514a69eef252bfdd13755748a00");
+    test_algorithm!(Sha3_224, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "02263bdf7bb8cacdb253e07ea5920477d0907128e98c050129df1671");
+    test_algorithm!(Sha3_224, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "8b2f7a685cc270fb157f7e72a39343c733546188641958d3a331f187");
+    test_algorithm!(Sha3_224, one_byte, U8_1_BYTE, "6e22f57104bb4b8dea42a97f517f12e9e28d667c97f2ca0c29d55f8a");
+    test_algorithm!(Sha3_224, two_bytes, U8_2_BYTES, "93887623e8501a3a931b7cf807984c63167779dd8b9117c0c5cb9c05");
+    test_algorithm!(Sha3_224, three_bytes, U8_3_BYTES, "7215402e97eaada2c85ae585a9b661341cc7755185a55d03753804a8");
+    test_algorithm!(Sha3_224, four_bytes, U8_4_BYTES, "22dd9cf1b3b0ed380cdbdd7ab4aa06dd8ec7cc1cb7e45848c20f916e");
+    test_algorithm!(Sha3_224, ascending, U8_32_ASCENDING, "7201e5c8a92380577c3d537c9d291e6977d252d20cb9f6d2f17ca595");
+    test_algorithm!(Sha3_224, descending, U8_32_DESCENDING, "ede5539316977e1893c00a2b0d9bdeea89cbaf3627d8b2da8f6f40c1");
+    test_algorithm_s!(Sha3_224, hi, S_HI, "4538aacc6ccae167eb462bd2d6ced3537edf6f8d88af709be7b130c0");
+    test_algorithm_s!(Sha3_224, quick_brown_fox, S_QUICK_BROWN_FOX, "558f221ac3d72656a2ace671ab06e599c7e5c2a4b5a739e77c2da1b4");
+    test_algorithm_s!(Sha3_224, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "d2478eb005b2c03e978aa475778ad49882073f8294da603761602b37");
+    test_algorithm_s!(Sha3_224, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "487a145695aafb74f1ec9a21da23d009da61c7bfe3813998d9cd079c");
+    test_keyed_algorithm_s!(Sha3_224, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "4055c7ce3498af09d7463a8f34f2d35329d0c2d1bfab10e337abd9d0");
+}
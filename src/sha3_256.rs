@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+#[allow(non_camel_case_types)]
+pub struct Sha3_256 {}
+
+impl Sha3_256 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 256,
+            name: "SHA3-256",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha3::Sha3_256>,
+            keying: hmac::<sha3::Sha3_256>("HMAC-SHA3-256"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha3_256;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Sha3_256, empty, U8_EMPTY, "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+    test_algorithm!(Sha3_256, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "9e6291970cb44dd94008c79bcaf9d86f18b4b49ba5b2a04781db7199ed3b9e4e");
+    test_algorithm!(Sha3_256, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "312103087049a11ebaf63037cdf84a75be2e244476c2b97cd5b2a813792facaa");
+    test_algorithm!(Sha3_256, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "01ed9271b2e7bfdfffb130d403daf002de33317d3806b47aab95fa686efa1689");
+    test_algorithm!(Sha3_256, one_byte, U8_1_BYTE, "42538602949f370aa331d2c07a1ee7ff26caac9cc676288f94b82eb2188b8465");
+    test_algorithm!(Sha3_256, two_bytes, U8_2_BYTES, "c965fbca52984ede166b9e99ab166e64d29c84bc5fd69a58f6028d5e9534efaa");
+    test_algorithm!(Sha3_256, three_bytes, U8_3_BYTES, "9722ba31a88874bde89ce580d20c2c4af98144007809c510f1aa1ca07a072b8a");
+    test_algorithm!(Sha3_256, four_bytes, U8_4_BYTES, "1556d9fe70303fe87e3b5b6a1672ddd1f27d0c521bd0c8d03c0ed9f1d211bf17");
+    test_algorithm!(Sha3_256, ascending, U8_32_ASCENDING, "08d76bb3d477d6f3a5f26cb66c691486547acf9bbac6cfabfba30784c815ae45");
+    test_algorithm!(Sha3_256, descending, U8_32_DESCENDING, "ddf742cbac08cd2f3e98fd657def37c7f20833711eabb18b2837202b61e24ab8");
+    test_algorithm_s!(Sha3_256, hi, S_HI, "b39c14c8da3b23811f6415b7e0b33526d7e07a46f2cf0484179435767e4a8804");
+    test_algorithm_s!(Sha3_256, quick_brown_fox, S_QUICK_BROWN_FOX, "cb5948f3de96e69e8633c0bc30eec2585ba3ba5adf5dcd0a286b43baeadfd82f");
+    test_algorithm_s!(Sha3_256, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "0b5eefcc2a59e4e5faee26fd65b7a8932f42ec2b2cf72f947a5d600629b50799");
+    test_algorithm_s!(Sha3_256, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "b709dff496f28492b5ce5baa554b78edee211d7a56ca4fbfe7f18373528e3100");
+    test_keyed_algorithm_s!(Sha3_256, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "6ad143004d7a7da2896e25c66e70d35025a9c0ec5efd73ba7955ff47e4cec002");
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+#[allow(non_camel_case_types)]
+pub struct Sha3_384 {}
+
+impl Sha3_384 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 384,
+            name: "SHA3-384",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha3::Sha3_384>,
+            keying: hmac::<sha3::Sha3_384>("HMAC-SHA3-384"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha3_384;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Sha3_384, empty, U8_EMPTY, "0c63a75b845e4f7d01107d852e4c2485c51a50aaaa94fc61995e71bbee983a2ac3713831264adb47fb6bd1e058d5f004");
+    test_algorithm!(Sha3_384, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "4bdaabf788d3ad1ad83d6d93c7e44937c2e6496af23be3354d756987f45160fc4023bda95ecdcb3c7e31a62f726d702c");
+    test_algorithm!(Sha3_384, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "7b44bd8f30777af37b7859da67909281d37cf2c643cce69a102f8be245ff121761de2dfb36427b236f5da6b1c6e4bb48");
+    test_algorithm!(Sha3_384, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "ac24819e104f1a0dfa4420b4c179de57fc4c267aa9d6ee3a5f2754358f5d4dce916134cef3eb8359aa1decfcc262dad3");
+    test_algorithm!(Sha3_384, one_byte, U8_1_BYTE, "114681f5af3e1c7be7bfb31f60b1bab2de762d43258a23f56f92dececb14949956dc9bda8f248b62a881299064600b4a");
+    test_algorithm!(Sha3_384, two_bytes, U8_2_BYTES, "af0b669f104943423c1f654c974e510334967fe72ca2f007558ca4dde2f948fda11cb10831b26d95e79ef68bc0abc08d");
+    test_algorithm!(Sha3_384, three_bytes, U8_3_BYTES, "46fd9fe9492799046f0e496c4a906bb5c69034753ecfbc0f97a3b2743d85a86467a453f3b24477d72093028d07b0cc81");
+    test_algorithm!(Sha3_384, four_bytes, U8_4_BYTES, "6f7d6ed9b66f0d4c355a8a7ed0324d1ababdb83a0ef175ffd3af8c0f8e9b0bf771c40630bf80b8be610dd19863d5e25d");
+    test_algorithm!(Sha3_384, ascending, U8_32_ASCENDING, "8240296acdd8bd0fd8df7dc60e10cb8bdd9c8c5689c4ec68173d997857ee312ff83932e34a6558144179a2973d3e6362");
+    test_algorithm!(Sha3_384, descending, U8_32_DESCENDING, "470f9053149bdf9a8614ae1fb0cd956c75f1a37e219b5271c09ad4dc38d2d5a900ea7f6d42777ec07ab8e4db20d16a91");
+    test_algorithm_s!(Sha3_384, hi, S_HI, "1a3f82224ae3c0933d97c7e2e5d48b73f93eadf91057a1b54d4ebdd9ec37bd4d73c8fb9b96515dabfc6fa0a33791f3d4");
+    test_algorithm_s!(Sha3_384, quick_brown_fox, S_QUICK_BROWN_FOX, "cd4f87c630b18cdbfc23db7e921861d3c65112c2e7f8bc7d07cc1f26d915b4e370c5c1b58c8840f20a91d6c588223e7a");
+    test_algorithm_s!(Sha3_384, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "925c17c8653f1bac2fa853b2e82391a21bd9e71360989d4564021e27e76370ba31c4c7a9afa87cd7bd5503053cb798e1");
+    test_algorithm_s!(Sha3_384, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "5c7c21a8e2220ef4c77063b14a10dbe7494b5989fc191c47dd6cc30af6a6e519bebd8baa0d1ea60a6de4f1c179ccd7e8");
+    test_keyed_algorithm_s!(Sha3_384, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "6858827e06bab8ce3c4a737ac16af337c09e3d86ebd31d16143aaa60585d2d4a690b720bdf725158df06614cc4793bac");
+}
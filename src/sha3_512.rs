@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+#[allow(non_camel_case_types)]
+pub struct Sha3_512 {}
+
+impl Sha3_512 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 512,
+            name: "SHA3-512",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha3::Sha3_512>,
+            keying: hmac::<sha3::Sha3_512>("HMAC-SHA3-512"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha3_512;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Sha3_512, empty, U8_EMPTY, "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26");
+    test_algorithm!(Sha3_512, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "ad56c35cab5063b9e7ea568314ec81c40ba577aae630de902004009e88f18da57bbdfdaaa0fc189c66c8d853248b6b118844d53f7d0ba11de0f3bfaf4cdd9b3f");
+    test_algorithm!(Sha3_512, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "99023add4048e96044ca624664effe8f51e46c0eec998223aa7424bf21e99b913afa4233ae572c0c3850ac4cbabb9f77785887d5c3b2b95a0d43912d7b815d01");
+    test_algorithm!(Sha3_512, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "5a944f3eaf5e8412fcffc48e01a313848cf95f511d0b0667332a4adc5803793a58fe6646d04fc2d9160a43c57ab68545e3298b3cb3ba2284e605edffda603287");
+    test_algorithm!(Sha3_512, one_byte, U8_1_BYTE, "6ebb8a73bfd0459bd575b9dbef6dcb970bb11182591f5ecd7c8c0d771b3269b715fcb84005d542ff74306565a46b3b893f64ca41b8519457ae137f6429dfbb1e");
+    test_algorithm!(Sha3_512, two_bytes, U8_2_BYTES, "42656340816c18ee7a47eecf4fe8e36d62271d7bde1a823271fbd76e7b85a6e786ab7eb5e7f92c6c755468d1b08085daf9236d3e2f1bcf19a320189567645722");
+    test_algorithm!(Sha3_512, three_bytes, U8_3_BYTES, "343a8ae594720746d9689dc3a2c4acee4f307c775d4ff44a05621d56b5bc2a4f5093d3576e935009f4aa3b7b582e2033efdaa08a650a28d31bebeaa9acf9bcaa");
+    test_algorithm!(Sha3_512, four_bytes, U8_4_BYTES, "02ee135ca0bb9a9888d662cdbbc748520c1467514aeb04afe3a80d622cd321e5b70c546b479d368994dd6db988ba188bc247522d1ff3409bbbb4edbd3f9ec61e");
+    test_algorithm!(Sha3_512, ascending, U8_32_ASCENDING, "340e03d7eb46e5568ba4ec3e48c3805d96015ba4108b3118707b9f314b0aa6deb7757d0d158acfc6490f853b251965bee120158a4b0fe7080164e9925beef09c");
+    test_algorithm!(Sha3_512, descending, U8_32_DESCENDING, "d47fdbbcdab7f4f81339c89abce0501e7d0aa414caeea2208de53294e531ab7207b383c3167014810460006f8f886ff49b943f7695645b1219a1bcb2542da0f7");
+    test_algorithm_s!(Sha3_512, hi, S_HI, "154013cb8140c753f0ac358da6110fe237481b26c75c3ddc1b59eaf9dd7b46a0a3aeb2cef164b3c82d65b38a4e26ea9930b7b2cb3c01da4ba331c95e62ccb9c3");
+    test_algorithm_s!(Sha3_512, quick_brown_fox, S_QUICK_BROWN_FOX, "748c824c9f21d1cd1faee7162ec4dfeb63754604f99b91066466aafe8367485400f79179c71c5a9b0719d7d01975aa717a7d3f30ad59d03a57e506027f0a5c08");
+    test_algorithm_s!(Sha3_512, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "c1081248482bfe376e60bfa82ebc4c076be210ea335584873c2d40e637002f5c9fc8d343baffb3e74ef6b45692f29a170ff934024f15e6d6472d22d186db3b82");
+    test_algorithm_s!(Sha3_512, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "1ded0c3c803aff509576ef2f20c1f0170ac510a44b6bd766de7e919401bb9270969d7aba244cde8d487cc190aa84394153796bfa783e5a576aaa478514750445");
+    test_keyed_algorithm_s!(Sha3_512, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "4d0ca610c79f2193f0169ab5d28293a17c26030b8ee4b85d384e4e88eaa508473424507b7c0509b615e3928d7a425bbd5d6bb7f79b4c62715825bbf154e5e7a4");
+}
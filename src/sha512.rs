@@ -1,14 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT-0
 
-use super::{to_calculated_digest, Algorithm, CalculatedDigest};
-use digest::Digest;
-use std::io::{Read, Result};
-
-fn sha512digest(a: &Algorithm, read: &mut dyn Read) -> Result<CalculatedDigest> {
-    let mut d = sha2::Sha512::new();
-    let bytes_read = std::io::copy(read, &mut d);
-    to_calculated_digest(a, bytes_read, &d.finalize())
-}
+use super::{hmac, new_hasher, Algorithm, Strength};
 
 pub struct Sha512 {}
 
@@ -17,7 +9,11 @@ impl Sha512 {
         Algorithm {
             digest_bit_size: 512,
             name: "SHA-512",
-            digest_fn: sha512digest,
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha2::Sha512>,
+            keying: hmac::<sha2::Sha512>("HMAC-SHA-512"),
         }
     }
 }
@@ -25,7 +21,7 @@ impl Sha512 {
 #[cfg(test)]
 mod tests {
     use super::Sha512;
-    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
     test_algorithm!(Sha512, empty, U8_EMPTY, "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e");
     test_algorithm!(Sha512, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "5046adc1dba838867b2bbbfdd0c3423e58b57970b5267a90f57960924a87f1960a6a85eaa642dac835424b5d7c8d637c00408c7a73da672b7f498521420b6dd3");
     test_algorithm!(Sha512, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "f0ba98521c6a724998b9dd342704c160b9b8dd2571b0aeed27b883c8b9c61c448545f3df33ebcdeefe8d6b7ab60faf4340755e1e6a8063e05e618408e5377c82");
@@ -40,4 +36,5 @@ mod tests {
     test_algorithm_s!(Sha512, quick_brown_fox, S_QUICK_BROWN_FOX, "e3cb25182765cbb590e0e47711e14179ddc2a6b29802fa25bb785042e351efeb6608af6c57d0db55e8cc6232be85ee61b4878604f783bd360757e41db16bc96e");
     test_algorithm_s!(Sha512, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "f0033ace51f9eff5acd73409f22ab30144ec70487b1b38b613f5eee46d199b3565648237b8af31b6b2cb94d22605edcf5f7e88d7b4a241fae52e5979d6034b4f");
     test_algorithm_s!(Sha512, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "a1aa08726c71bf0d8d0b9f380fc4000806a8a0be52a5a665d62558861c96725b8f8b3c5453082d05327d2d580f6b586cef8f87a3c3d8ed1efca8e5793b166ebb");
+    test_keyed_algorithm_s!(Sha512, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "0fefdba2aaf7bec69881313de2c453a1023e2d6d3bfe713c54d06e2e50f05ae035f26df376ead8599af480df773a15b930a0d28f13b18bfa546044e10f06b001");
 }
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+#[allow(non_camel_case_types)]
+pub struct Sha512_224 {}
+
+impl Sha512_224 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 224,
+            name: "SHA-512/224",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha2::Sha512_224>,
+            keying: hmac::<sha2::Sha512_224>("HMAC-SHA-512/224"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha512_224;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Sha512_224, empty, U8_EMPTY, "6ed0dd02806fa89e25de060c19d3ac86cabb87d6a0ddd05c333b84f4");
+    test_algorithm!(Sha512_224, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "9e7d6080def4e1ccf4aeaac6f7fad008d060a6cf87062038d6166774");
+    test_algorithm!(Sha512_224, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "a55d41481fb8d5e561f911e2b8f87d0e165095bbd3538f15095575d7");
+    test_algorithm!(Sha512_224, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "d7737490217f4f1a164e0f30807f464b7e3bb5b86313df9781bd032a");
+    test_algorithm!(Sha512_224, one_byte, U8_1_BYTE, "dba66db326c9933b589eed68e604cfacf7d9ed99fd5742c634f30374");
+    test_algorithm!(Sha512_224, two_bytes, U8_2_BYTES, "96c690f1f35a3172e89a6969a63cb0ae4ac1a179a9778c150249eba9");
+    test_algorithm!(Sha512_224, three_bytes, U8_3_BYTES, "29872881721caec59eb095bf2867bfaa349ed8d8aeb0e57995d5dcee");
+    test_algorithm!(Sha512_224, four_bytes, U8_4_BYTES, "efae7d095629ad479beee7d27f37da5ae7209d77c707a0bd44f5511e");
+    test_algorithm!(Sha512_224, ascending, U8_32_ASCENDING, "5c9a24b3f0d34ec1945f2af0a51d2388c7ac8214302b5820ad8f0bf0");
+    test_algorithm!(Sha512_224, descending, U8_32_DESCENDING, "d42ac5caa30d0422535a5c04bbc43c51c4f6d3a8270eb1053365685d");
+    test_algorithm_s!(Sha512_224, hi, S_HI, "626d63db61db32fc10c9f6a91158478481fa75aa665aed36050e360b");
+    test_algorithm_s!(Sha512_224, quick_brown_fox, S_QUICK_BROWN_FOX, "f029310e4a7fb0f21e6546885b0c25e7331753fdf008c07ba5b52b5c");
+    test_algorithm_s!(Sha512_224, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "46274c2d539f12599ecb1eddeca09e92827c3893035f888c2aee4b10");
+    test_algorithm_s!(Sha512_224, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "473fe42d2467f35e4bfc230cff8f62b84e0646fd118a3ce8d98b30a0");
+    test_keyed_algorithm_s!(Sha512_224, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "8d1044c0b661276223136c8b0285fd6936f97c25cc23ddc4b0615a5c");
+}
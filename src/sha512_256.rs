@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+#[allow(non_camel_case_types)]
+pub struct Sha512_256 {}
+
+impl Sha512_256 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 256,
+            name: "SHA-512/256",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sha2::Sha512_256>,
+            keying: hmac::<sha2::Sha512_256>("HMAC-SHA-512/256"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sha512_256;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Sha512_256, empty, U8_EMPTY, "c672b8d1ef56ed28ab87c3622c5114069bdd3ad7b8f9737498d0c01ecef0967a");
+    test_algorithm!(Sha512_256, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "af13c048991224a5e4c664446b688aaf48fb5456db3629601b00ec160c74e554");
+    test_algorithm!(Sha512_256, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "67ddf9a84ba52b20d9c345a78534178b54f29a38adae5a5a92f76aa73fcbcf45");
+    test_algorithm!(Sha512_256, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "1828f633c45a9a4284c73c213514870c976ff2d042f4747780968ab8c64934ad");
+    test_algorithm!(Sha512_256, one_byte, U8_1_BYTE, "a051935849c2d28415e06d56c4fbc4d7672d36a10f7b58396011998f34dcbf29");
+    test_algorithm!(Sha512_256, two_bytes, U8_2_BYTES, "769d0246dfa540f7f9fd020b3be19f69d807efd38eb917425caedc90e1a7c97e");
+    test_algorithm!(Sha512_256, three_bytes, U8_3_BYTES, "628d69e4c93eaeab9033144033b214f3ab1952f4ff2ea66ab6f6388cb120b669");
+    test_algorithm!(Sha512_256, four_bytes, U8_4_BYTES, "5a7d25c3b22ae276c785a60041690d19f8f683a13f4274710039c9b2c38b9645");
+    test_algorithm!(Sha512_256, ascending, U8_32_ASCENDING, "86d447c968b3c8ee2c5eb0174c236d7d38b15f14fa4dc3415d5f311739e15b08");
+    test_algorithm!(Sha512_256, descending, U8_32_DESCENDING, "3d23f84459b7d1e8461f76982b67f062c55efeefb844472f95042b218be8dd95");
+    test_algorithm_s!(Sha512_256, hi, S_HI, "8ab98d4dbaa10dbf48a1ee02367c967e39987e5096b90a4067a242b470be755a");
+    test_algorithm_s!(Sha512_256, quick_brown_fox, S_QUICK_BROWN_FOX, "c644b0253648e98c6a65f3d055dbec66105e315914d78b3546f8223fce10e65a");
+    test_algorithm_s!(Sha512_256, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "939e06e63c85e0ca91605f3f5cb517dc1fb64e0a1a985804b81677f0bcef33cc");
+    test_algorithm_s!(Sha512_256, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "cb3a19ba163d588e988554382a9474c11ebdc4ca13c2579d1b22f2ee304d7c3e");
+    test_keyed_algorithm_s!(Sha512_256, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "3fa68e643d91a8c653be3d8aed3bae187e839353d00bb8705b18c78100d63993");
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{new_xof_hasher, Algorithm, Strength};
+
+/// Produces a 256 bit digest by default.  Use `Algorithm::with_digest_bit_size` for other lengths.
+pub struct Shake128 {}
+
+impl Shake128 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 256,
+            name: "SHAKE128",
+            extendable_output: true,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_xof_hasher::<sha3::Shake128>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Shake128;
+    use crate::{test_algorithm, test_algorithm_s, tests::*, Strength};
+    test_algorithm!(Shake128, empty, U8_EMPTY, "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26");
+    test_algorithm!(Shake128, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "24a7ca4b75e3898d4f12e74dea8cbb650733bd34525b281e4b6488d4291c0fdb");
+    test_algorithm!(Shake128, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "33737cf5c5f45c165784491454c03eaecc2497752a1a170a07e8c72399eff4f2");
+    test_algorithm!(Shake128, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "44de86222faf16228cfdbab3d93572cd3ea0745983ea0258c8be121e85744f35");
+    test_algorithm!(Shake128, one_byte, U8_1_BYTE, "bb2b536f0606a0910bf4b98c74c75e966d0fbcd792ca55e3c768ca2e92234d50");
+    test_algorithm!(Shake128, two_bytes, U8_2_BYTES, "d8e047e52d31b238cc852d2f193e7f8aef0e0937d06057d6c138869dcbc2cfe4");
+    test_algorithm!(Shake128, three_bytes, U8_3_BYTES, "905891d8485a0227463a22add3e68e22e817bc76a3b24b0bbf1372176e44379f");
+    test_algorithm!(Shake128, four_bytes, U8_4_BYTES, "092123fb5f0174cadde49c4e87606a837f3228f35911b281189254b586aa5bfd");
+    test_algorithm!(Shake128, ascending, U8_32_ASCENDING, "5d52e8465ab88e1779801a3af13071dddbbc5fd194bb6b4e29d2fe452e584ba8");
+    test_algorithm!(Shake128, descending, U8_32_DESCENDING, "41e13a742ad4fe0cf48fad64a862a8b23cc5ef27e27a6914b30deef4c111beb6");
+    test_algorithm_s!(Shake128, hi, S_HI, "f320eed5c555a61af1c6a7174cdfc4ef9332431935d99e786f6e34235db8b806");
+    test_algorithm_s!(Shake128, quick_brown_fox, S_QUICK_BROWN_FOX, "2a9aad3081e75cb6213616f7c33f3de248b099cedb8264822d130e636e423ffa");
+    test_algorithm_s!(Shake128, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "cae314732dd0d2d020b6894bf0761ba95d4ae1025b0c98d127e452962fc44b2f");
+    test_algorithm_s!(Shake128, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "0d73f9df1e16f11ebd6d33e59458b4459c4fa3a8a3ffdde205816481a7332901");
+
+    #[test]
+    fn other_digest_bit_size() {
+        let algorithm = Shake128::new().with_digest_bit_size(224).unwrap();
+        crate::tests::test_algorithm(
+            &algorithm,
+            &mut S_QUICK_BROWN_FOX.as_bytes(),
+            S_QUICK_BROWN_FOX.len() as u64,
+            "2a9aad3081e75cb6213616f7c33f3de248b099cedb8264822d130e63",
+        );
+        assert_eq!(algorithm.strength, Strength::Strong);
+    }
+
+    #[test]
+    fn digest_bit_size_must_be_at_least_224() {
+        assert!(Shake128::new().with_digest_bit_size(216).is_none());
+        assert!(Shake128::new().with_digest_bit_size(8).is_none());
+    }
+
+    #[test]
+    fn digest_bit_size_must_be_whole_bytes() {
+        assert!(Shake128::new().with_digest_bit_size(252).is_none());
+    }
+}
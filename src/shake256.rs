@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{new_xof_hasher, Algorithm, Strength};
+
+/// Produces a 512 bit digest by default.  Use `Algorithm::with_digest_bit_size` for other lengths.
+pub struct Shake256 {}
+
+impl Shake256 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 512,
+            name: "SHAKE256",
+            extendable_output: true,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_xof_hasher::<sha3::Shake256>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Shake256;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Shake256, empty, U8_EMPTY, "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be");
+    test_algorithm!(Shake256, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "f5977c8283546a63723bc31d2619124f11db4658643336741df81757d5ad3062221e124311ec7f7181568de7938df805d894f5fded465001a04e260a49482cf5");
+    test_algorithm!(Shake256, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "85f6e9065ac75e5cf7f202ab30bb0a2b8c29db3589218bbb7ddcdd4bde83f2b4160479f568820bdb30331702fb0f3a1714476e725c07b7a9579d09fe67fbe3a2");
+    test_algorithm!(Shake256, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "e254743c0419de227cb267f1a0d32d6c1526cdc6060f43add312ae47de592256e51548dedf4554e2d1dcff072ab9c90a8713b7ddaa3be9d24caa9ecb73c8661b");
+    test_algorithm!(Shake256, one_byte, U8_1_BYTE, "24e123d7538cf7a23c8f8e6ed94b7e0f8edaf7aa4a10957583373c341fd4d1e3bbda089f5e9afb83b382ff2dc2fdeb9b927aad18fcbd69bf3cba9dd37fa46e02");
+    test_algorithm!(Shake256, two_bytes, U8_2_BYTES, "ad8250a8b700b04b9746fa675262821e6aa75e3258a29c8109c1334a7f4871ce123a1c6d1dbfcfa8426de2b6ad9538e902020f8109e02fa7d8dc3501e2e879d0");
+    test_algorithm!(Shake256, three_bytes, U8_3_BYTES, "168344c3dca05af91efa263801fd5ac537ae8b6a465ef43cc4021de11b1402d81a88e55ae47060e536695d68bbb8afc0c1e80995b9a6b0aec3defff1792f45c4");
+    test_algorithm!(Shake256, four_bytes, U8_4_BYTES, "9b527bd3a7bd90048d0425ea67cc275565ef7431be9d1fd6b40132e7ce4ccbf2b3bc65a519106b40ba4cc48095910f1622991c81611b681e989449891fef8cee");
+    test_algorithm!(Shake256, ascending, U8_32_ASCENDING, "f6a704e0fa1de28f681dd2fb0835391aadd2f114b6521c7bf69fc4f9e5de4ffebf4c82fc872d7c6f64b8547035f9d37b7a6041eec4059df97f5e7d0a3a9e6017");
+    test_algorithm!(Shake256, descending, U8_32_DESCENDING, "b44fd6b7e2b303416903f4fd496eb6a521e6010cc0dac28d4dfb3784395f167bf1ff66480162e6b19b945aff235a309f65c9ba40fc09f068caf3c66311ec5177");
+    test_algorithm_s!(Shake256, hi, S_HI, "8b63b4da23d23e65957b0d6d88c64172dff5818946b41a3c9cd2a24c31c82c90198805c27dcb89e5bc2d5072e9b89132d702e8eefde27d5595ac0940f247068c");
+    test_algorithm_s!(Shake256, quick_brown_fox, S_QUICK_BROWN_FOX, "fd9a4ad627ee682c9701c02d927a926bac947c2f14e51af949ebb972c94caee2b2cc9edbb48f9f897b33b931ef4a2a80c856f6d45ba623844e7511f9fe67ae5d");
+    test_algorithm_s!(Shake256, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "b154ee666dd78f47e721f44d54c184013e0355c92b30dc39f147da5d150cc3c655d1cb72441a687a91f1f67e3cd83bae802d85da4acc9c34afca207cbdde77c7");
+    test_algorithm_s!(Shake256, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "0cce1681c411794eac04b8998ba64b9e9bcee7fc0fea300e895ad37aef80de977a54ac8f0c9b8155b2914e23e22f29f46dc7f98fafec6b15bc388ee5abebafe9");
+
+    #[test]
+    fn other_digest_bit_size() {
+        let algorithm = Shake256::new().with_digest_bit_size(256).unwrap();
+        crate::tests::test_algorithm(
+            &algorithm,
+            &mut S_QUICK_BROWN_FOX.as_bytes(),
+            S_QUICK_BROWN_FOX.len() as u64,
+            "fd9a4ad627ee682c9701c02d927a926bac947c2f14e51af949ebb972c94caee2",
+        );
+    }
+
+    #[test]
+    fn digest_bit_size_must_be_whole_bytes() {
+        assert!(Shake256::new().with_digest_bit_size(252).is_none());
+    }
+}
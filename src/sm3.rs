@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+/// SM3, the Chinese national standard hash (GB/T 32905-2016).
+pub struct Sm3 {}
+
+impl Sm3 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 256,
+            name: "SM3",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<sm3::Sm3>,
+            keying: hmac::<sm3::Sm3>("HMAC-SM3"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sm3;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Sm3, empty, U8_EMPTY, "1ab21d8355cfa17f8e61194831e81a8f22bec8c728fefb747ed035eb5082aa2b");
+    test_algorithm!(Sm3, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "e0bab8f4d8172ba245190d13c94117e93b82166c25b2b69883350c192c905140");
+    test_algorithm!(Sm3, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "0d59ac0ad08c972ade35500e4e14837df5ffba70ef4704afa2fa1e06e2339cd6");
+    test_algorithm!(Sm3, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "19181d1c68b3507b2b7755b5daa320282d6ea943b483794d02894126ca05d61c");
+    test_algorithm!(Sm3, one_byte, U8_1_BYTE, "6d5f8a0d6dbead526469b858620b970ee6b3d3f7950f950f6848777194bfe105");
+    test_algorithm!(Sm3, two_bytes, U8_2_BYTES, "a332b20ea860d6aad5b17e16f283d432b3ce247f5cee38a065dbc605c949cc6c");
+    test_algorithm!(Sm3, three_bytes, U8_3_BYTES, "b92020bd67d9bba0e88aea3bd6adc596b4966a12910a2f278f07ca7cabfc813b");
+    test_algorithm!(Sm3, four_bytes, U8_4_BYTES, "40a22c87273c4808df6879c95ff30b646346d68220fc2bcb9b7aa8ba010880c2");
+    test_algorithm!(Sm3, ascending, U8_32_ASCENDING, "dac66fce0b5ba0f48074e356b35752ed6a93b1a340fc85240a9e1a0bf7308394");
+    test_algorithm!(Sm3, descending, U8_32_DESCENDING, "01712f6bda1a53f690f105f5c83e384f051577f301fbd493ec725b3bd45cac46");
+    test_algorithm_s!(Sm3, hi, S_HI, "4338825cb5c6d964d8a00c53badaa7f1dafd911e32d6d65e39f9c9be6d10605a");
+    test_algorithm_s!(Sm3, quick_brown_fox, S_QUICK_BROWN_FOX, "11959bbf07845e2062d355611bbe713c690aec74cf943124053133bd2fb3860b");
+    test_algorithm_s!(Sm3, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "823810557d489d935bed9dadc986062d9c641aaa806536f27dcedfddc432b28d");
+    test_algorithm_s!(Sm3, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "569c11eb46b7f15fafebd759688a089cd263ca5bc3509c89970ef718454eb3d3");
+    test_algorithm_s!(Sm3, abc, "abc", "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0");
+    test_keyed_algorithm_s!(Sm3, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "e8765cfa7448a177340e7e8a30336bee506239922cbba9775426f73342af03e2");
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::encoding::{DecodedDigest, DigestEncoding};
+
+/// One hash from a Subresource Integrity (SRI) string such as `sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQl...`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntegrityMetadata {
+    pub algorithm_name: &'static str,
+    pub encoded_digest: String,
+    pub digest: DecodedDigest,
+}
+
+/// The SRI hash algorithm tokens and the names of the matching algorithms, from weakest to strongest.
+const INTEGRITY_ALGORITHMS: [(&str, &str); 3] = [("sha256", "SHA-256"), ("sha384", "SHA-384"), ("sha512", "SHA-512")];
+
+fn strength(algorithm_name: &str) -> usize {
+    INTEGRITY_ALGORITHMS
+        .iter()
+        .position(|(_, name)| *name == algorithm_name)
+        .unwrap_or_default()
+}
+
+/// Parses a whitespace separated list of SRI hashes.  As the SRI spec requires, hashes using an unsupported algorithm
+/// or that aren't well-formed are ignored and any `?options` are discarded.  An empty result means there is no usable
+/// integrity metadata.
+pub fn parse_integrity(integrity: &str) -> Vec<IntegrityMetadata> {
+    integrity.split_whitespace().filter_map(parse_hash).collect()
+}
+
+fn parse_hash(hash: &str) -> Option<IntegrityMetadata> {
+    let (token, rest) = hash.split_once('-')?;
+    let encoded_digest = rest.split_once('?').map_or(rest, |(digest, _)| digest);
+    let (_, algorithm_name) = INTEGRITY_ALGORITHMS
+        .iter()
+        .find(|(t, _)| t.eq_ignore_ascii_case(token))?;
+    let digest = [DigestEncoding::Base64, DigestEncoding::Base64Url]
+        .iter()
+        .find_map(|encoding| {
+            encoding.decode(encoded_digest).map(|bytes| DecodedDigest {
+                encoding: *encoding,
+                bytes,
+            })
+        })?;
+    Some(IntegrityMetadata {
+        algorithm_name,
+        encoded_digest: encoded_digest.to_string(),
+        digest,
+    })
+}
+
+/// Returns only the hashes that use the strongest algorithm present.  Per the SRI spec, content matches if it matches
+/// any one of these.
+pub fn strongest_metadata(metadata: &[IntegrityMetadata]) -> Vec<IntegrityMetadata> {
+    let strongest = metadata.iter().map(|m| strength(m.algorithm_name)).max();
+    metadata
+        .iter()
+        .filter(|m| Some(strength(m.algorithm_name)) == strongest)
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_integrity, strongest_metadata};
+
+    const SHA384_INTEGRITY: &str = "sha384-oqVuAfXRKap7fdgcCY5uykM6+R9GqQ8K/uxy9rx7HNQlGYl1kPzQho1wx4JwY8wC";
+
+    #[test]
+    fn single_hash() {
+        let metadata = parse_integrity(SHA384_INTEGRITY);
+        assert_eq!(metadata.len(), 1);
+        assert_eq!(metadata[0].algorithm_name, "SHA-384");
+        assert_eq!(metadata[0].digest.bit_len(), 384);
+        assert_eq!(
+            hex::encode(&metadata[0].digest.bytes),
+            "a2a56e01f5d129aa7b7dd81c098e6eca433af91f46a90f0afeec72f6bc7b1cd42519897590fcd0868d70c7827063cc02"
+        );
+    }
+
+    #[test]
+    fn options_and_unknown_algorithms_are_ignored() {
+        let metadata = parse_integrity(&format!(
+            "md5-1B2M2Y8AsgTpgAmY7PhCfg== {}?ct=application/javascript",
+            SHA384_INTEGRITY
+        ));
+        assert_eq!(metadata.len(), 1);
+        assert_eq!(metadata[0].algorithm_name, "SHA-384");
+    }
+
+    #[test]
+    fn invalid_integrity() {
+        assert!(parse_integrity("").is_empty());
+        assert!(parse_integrity("sha384").is_empty());
+        assert!(parse_integrity("sha384-!!!").is_empty());
+        assert!(parse_integrity("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855").is_empty());
+    }
+
+    #[test]
+    fn strongest_first() {
+        let metadata = parse_integrity(&format!(
+            "sha256-47DEQpj8HBSa+/TImW+5JCeuQeRkm5NMpJWZG3hSuFU= {} sha512-z4PhNX7vuL3xVChQ1m2AB9Yg5AULVxXcg/SpIdNs6c5H0NE8XYXysP+DGNKHfuwvY7kxvUdBeoGlODJ6+SfaPg== sha512-AAAA",
+            SHA384_INTEGRITY
+        ));
+        assert_eq!(metadata.len(), 4);
+        let strongest = strongest_metadata(&metadata);
+        assert_eq!(strongest.len(), 2);
+        assert!(strongest.iter().all(|m| m.algorithm_name == "SHA-512"));
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+/// The 256-bit Streebog, the Russian national standard hash GOST R 34.11-2012.
+pub struct Streebog256 {}
+
+impl Streebog256 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 256,
+            name: "Streebog-256",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<streebog::Streebog256>,
+            keying: hmac::<streebog::Streebog256>("HMAC-Streebog-256"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Streebog256;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Streebog256, empty, U8_EMPTY, "3f539a213e97c802cc229d474c6aa32a825a360b2a933a949fd925208d9ce1bb");
+    test_algorithm!(Streebog256, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "278c88971d79b7ec0173b35ff38fdeb3ce41504c7afc301b10751781814b6c12");
+    test_algorithm!(Streebog256, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "148667970cfbda4cb05ec38e265230358f8d1c62f3de7a250bce3a7bee32a8c7");
+    test_algorithm!(Streebog256, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "2b333c4f7548a58defccfb1b342f71220c08789a849b5beb6374b8710dc9972d");
+    test_algorithm!(Streebog256, one_byte, U8_1_BYTE, "1d2e06771c2d4e58125a8226b27f70249bc07c6e39eb9c3d02011b0760c6be97");
+    test_algorithm!(Streebog256, two_bytes, U8_2_BYTES, "240770ad934d77ddeac848ef904711ce97a1a58c87bc0dddae9efd7199c27b1e");
+    test_algorithm!(Streebog256, three_bytes, U8_3_BYTES, "5c3c4aa8f4a701af23e4c3a1c0a6271f8719180ef4c1b1ad87267f81c9782e20");
+    test_algorithm!(Streebog256, four_bytes, U8_4_BYTES, "42daa0e959a805154a08820d5d36aeac92c487ab67fb9969b7294774086afe3f");
+    test_algorithm!(Streebog256, ascending, U8_32_ASCENDING, "aafc59335b7af10327aac9f3869a7dafd70c1ca9974f71c3670af49ba08bc29e");
+    test_algorithm!(Streebog256, descending, U8_32_DESCENDING, "11c88e1ef26e750bc29905b9da149a22ba738c3cbe4b135358753a518f4a27c9");
+    test_algorithm_s!(Streebog256, hi, S_HI, "66d3503f682f56a79544d93a49efdb5b2061e7a4c40140fc68a677b9e072aae6");
+    test_algorithm_s!(Streebog256, quick_brown_fox, S_QUICK_BROWN_FOX, "f28523776348546b5c5f1855f797af08309e7776256159d86ccc00e8261e9702");
+    test_algorithm_s!(Streebog256, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "5f780e7978d5b5da4cdd0ebef9b5bfbb69c66867c0e2294479c3e197714265d3");
+    test_algorithm_s!(Streebog256, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "6dbfcb4f8ffcb80ec396ba60395d2dbd8494b1c0040dc25fda24b09eb6d32942");
+    test_algorithm_s!(Streebog256, gost_r_34_11_2012_example_1, "012345678901234567890123456789012345678901234567890123456789012", "9d151eefd8590b89daa6ba6cb74af9275dd051026bb149a452fd84e5e57b5500");
+    test_keyed_algorithm_s!(Streebog256, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "826babd099240d624f9efad9fa484f7ab77ca8fb271b397820ac0a37bd5f88ea");
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+/// The 512-bit Streebog, the Russian national standard hash GOST R 34.11-2012.
+pub struct Streebog512 {}
+
+impl Streebog512 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 512,
+            name: "Streebog-512",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Strong,
+            hasher_fn: new_hasher::<streebog::Streebog512>,
+            keying: hmac::<streebog::Streebog512>("HMAC-Streebog-512"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Streebog512;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Streebog512, empty, U8_EMPTY, "8e945da209aa869f0455928529bcae4679e9873ab707b55315f56ceb98bef0a7362f715528356ee83cda5f2aac4c6ad2ba3a715c1bcd81cb8e9f90bf4c1c1a8a");
+    test_algorithm!(Streebog512, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "2dd23801b96943cb75b90fce9f101c4c5ec016ef07a786639d6aa0229635623f594e4c4826382a6dcdf76c39809e05e8f01313902aebafa5d9cc8a11c310b0e5");
+    test_algorithm!(Streebog512, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "0f501399a1ab6abeaad00d5b3b1d0688b9cfdb0880640283aabbaf3a6f5f1f9610fcb091857d2d97451de34dd101e414f7fe3130bee7dfa7f0707b7620f736a0");
+    test_algorithm!(Streebog512, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "7ab5695caf76994bc5d22a954831f78ddedf0b882f60daca39a6ff3a64ee222ba0730e909a5887ea07f25cc8a838c642439dc42505b2d290fb7907721d7f3186");
+    test_algorithm!(Streebog512, one_byte, U8_1_BYTE, "d7505d589a93ac86c01fa71b04d9ec000bae1400ca94751613574d052ae77cb94a272e88d78948349e06958bf8a42a0cc5737adda1a4e322c17765c71b7f6584");
+    test_algorithm!(Streebog512, two_bytes, U8_2_BYTES, "d1613c49b4ee5a829d48d53bec0f6a468c5b37a7181ed3d14475b11391bff28ae833853973dd0b83ea0ebe3ff9c1953a927a070029cbf825c1154b3d1f3a441c");
+    test_algorithm!(Streebog512, three_bytes, U8_3_BYTES, "22fda971eee546a840c8fb26491602517200782cc9d48ffb0fe2cc11d8d6172f933c9936455f81aa2610268642f85bb1f45f65b8435c7f1fb29f45ea93e737ec");
+    test_algorithm!(Streebog512, four_bytes, U8_4_BYTES, "db740ebdcc914d288d56d2a3e7d283261c4e9c6090f1a35ceceffdb3f5b8dabe58f1999353ce0464f4c9faf5b5eb56512329cfb43207bcd1d8e73df92657d507");
+    test_algorithm!(Streebog512, ascending, U8_32_ASCENDING, "f7be66d8068c124da2b31583d8002a500cc27b5b2e068bd3693cdd9658145ba6d5d49bc8724c4faa055530b5f4a751d75a3058c0ac34faca3682004e36d4ed0e");
+    test_algorithm!(Streebog512, descending, U8_32_DESCENDING, "f83c3ba93903f3b67012702270e5ba72477d2534ea50e8c58f4b524088c74eb9cdf647c406cee952ef29421fe343c89ff36ad17caa5420d31239181831723bd1");
+    test_algorithm_s!(Streebog512, hi, S_HI, "3a2c487acb364d48b7a99ea409b0ee1bb57abcbdb7ee701c434d4a5f9ec976e6dffec521867b59634b95acd23e4738db5943994bd49cbeedadeb6896512cfbb3");
+    test_algorithm_s!(Streebog512, quick_brown_fox, S_QUICK_BROWN_FOX, "e7ad9eb77a43bb346a1371822cccda7045b958285661f37dd9d300363b59ec862085e9d2b81ea5ad5e09d20ffb1ccf3b9470226b9b779d75f2e03dd67c2614d6");
+    test_algorithm_s!(Streebog512, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "ec32d92a39168351b3372e73556a5cd8868de3bdcf5f457aa597bcb0363164cfc2ee0dbc77ae481752e5a3f4d3063e6917cbffd7da2b2e572a5f4e9566124050");
+    test_algorithm_s!(Streebog512, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "a68cdf761e0c5251db5f0847ed0a132c6cbff6b549350eabc08e43f2b37b192b3938fb364aa91cfb4eb6959429f712144587a76ca051f19f4f62e1555f245b52");
+    test_algorithm_s!(Streebog512, gost_r_34_11_2012_example_1, "012345678901234567890123456789012345678901234567890123456789012", "1b54d01a4af5b9d5cc3d86d68d285462b19abc2475222f35c085122be4ba1ffa00ad30f8767b3a82384c6574f024c311e2a481332b08ef7f41797891c1646f48");
+    test_keyed_algorithm_s!(Streebog512, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "73f61b06d8a383d2fef6afbe1ca7a9bba2f291a95e3cb7c38cede567ce859daf561143a8394cdf74acc461f51707ec94d071e8dbbdfa0ecb02fbee0c9a27af85");
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+/// How far a match with an algorithm's digest can be trusted, from weakest to strongest.  Anything weaker than
+/// `Strong` doesn't rule out the content having been deliberately tampered with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Strength {
+    /// A checksum, like a CRC or xxHash, that detects accidental corruption but is easily forged
+    NonCryptographic,
+    /// A cryptographic hash with practical collision attacks, like MD5 and SHA-1
+    Broken,
+    /// A cryptographic hash without practical attacks that's no longer recommended, like RIPEMD-160
+    Legacy,
+    /// A cryptographic hash that's recommended today, like SHA-256
+    Strong,
+}
+
+impl Strength {
+    /// All strengths, from weakest to strongest.
+    pub const ALL: [Strength; 4] = [
+        Strength::NonCryptographic,
+        Strength::Broken,
+        Strength::Legacy,
+        Strength::Strong,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strength::NonCryptographic => "non-cryptographic",
+            Strength::Broken => "broken",
+            Strength::Legacy => "legacy",
+            Strength::Strong => "strong",
+        }
+    }
+
+    /// Looks up a strength by its `name`, ignoring case.
+    pub fn from_name(name: &str) -> Option<Strength> {
+        Strength::ALL.into_iter().find(|s| s.name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn is_weak(&self) -> bool {
+        *self < Strength::Strong
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Strength;
+
+    #[test]
+    fn names() {
+        for strength in Strength::ALL {
+            assert_eq!(Strength::from_name(strength.name()), Some(strength));
+        }
+        assert_eq!(
+            Strength::from_name("Non-Cryptographic"),
+            Some(Strength::NonCryptographic)
+        );
+        assert_eq!(Strength::from_name("weak"), None);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Strength::NonCryptographic < Strength::Broken);
+        assert!(Strength::Broken < Strength::Legacy);
+        assert!(Strength::Legacy < Strength::Strong);
+        assert!(Strength::Legacy.is_weak());
+        assert!(!Strength::Strong.is_weak());
+    }
+}
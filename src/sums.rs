@@ -0,0 +1,509 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::encoding::{is_decimal, CrcRepresentation};
+use crate::{CalculatedDigest, Error};
+use data_encoding::BASE64;
+use std::io::BufRead;
+
+/// A single line of a checksum file, either in the format written by GNU coreutils `sha256sum`, `md5sum`, etc. or in
+/// the BSD/`--tag` format written by `shasum --tag`, BSD `sha256`, `openssl dgst`, etc.  Only the latter names the
+/// algorithm, apart from the 64-bit XXH3 lines `xxhsum` writes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChecksumEntry {
+    pub algorithm_name: Option<String>,
+    pub digest: String,
+    pub file_name: String,
+    pub binary: bool,
+}
+
+/// The entries of a checksum file, along with the numbers of any lines that couldn't be parsed and were skipped.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChecksumFile {
+    pub entries: Vec<ChecksumEntry>,
+    pub malformed_lines: Vec<usize>,
+}
+
+/// Parses a single `<hex>  <name>`, `<hex> *<name>`, `<ALGO> (<name>) = <hex>` or POSIX `cksum` `<crc> <size> <name>`
+/// line.  Base64 digests, as written by `sha256sum --base64`, are accepted in place of hex.  Lines starting with a
+/// backslash have their file name unescaped the same way coreutils does (`\\` and `\n`).  Returns `None` if the line
+/// isn't in any of those formats.
+pub fn parse_line(line: &str) -> Option<ChecksumEntry> {
+    let (escaped, line) = match line.strip_prefix('\\') {
+        Some(rest) => (true, rest),
+        None => (false, line),
+    };
+    let mut entry = parse_tag_line(line)
+        .or_else(|| parse_cksum_line(line))
+        .or_else(|| parse_gnu_line(line))?;
+    if escaped {
+        entry.file_name = unescape_file_name(&entry.file_name)?;
+    }
+    Some(entry)
+}
+
+/// `xxhsum` prefixes 64-bit XXH3 digests in GNU style lines with `XXH3_`, since they're the same length as XXH64.
+const XXH3_PREFIX: &str = "XXH3_";
+
+fn parse_gnu_line(line: &str) -> Option<ChecksumEntry> {
+    let (digest, rest) = line.split_once(' ')?;
+    let (algorithm_name, digest) = match digest.strip_prefix(XXH3_PREFIX) {
+        Some(digest) => (Some(String::from("XXH3")), digest),
+        None => (None, digest),
+    };
+    if !is_checksum_digest(digest) {
+        return None;
+    }
+    let (binary, file_name) = match rest.chars().next() {
+        Some('*') => (true, &rest[1..]),
+        Some(' ') => (false, &rest[1..]),
+        _ => (false, rest),
+    };
+    if file_name.is_empty() {
+        return None;
+    }
+    Some(ChecksumEntry {
+        algorithm_name,
+        digest: digest.to_string(),
+        file_name: file_name.to_string(),
+        binary,
+    })
+}
+
+/// Parses a BSD/`--tag` style `<ALGO> (<name>) = <hex>` line.  The spacing OpenSSL uses, `<ALGO>(<name>)= <hex>`, is
+/// also accepted.
+pub fn parse_tag_line(line: &str) -> Option<ChecksumEntry> {
+    let (algorithm_name, rest) = line.split_once('(')?;
+    let algorithm_name = algorithm_name.trim_end();
+    let (file_name, digest) = rest.rsplit_once(')')?;
+    let digest = digest.trim_start().strip_prefix('=')?.trim();
+    if algorithm_name.is_empty() || algorithm_name.contains(char::is_whitespace) || file_name.is_empty() {
+        return None;
+    }
+    if !is_checksum_digest(digest) {
+        return None;
+    }
+    Some(ChecksumEntry {
+        algorithm_name: Some(algorithm_name.to_string()),
+        digest: digest.to_string(),
+        file_name: file_name.to_string(),
+        binary: true,
+    })
+}
+
+/// Parses a `<crc> <size> <name>` line as written by POSIX `cksum`.  The CRC is left in decimal, so it's verified
+/// with `CalculatedDigest::verify_crc`.
+pub fn parse_cksum_line(line: &str) -> Option<ChecksumEntry> {
+    let (crc, rest) = line.split_once(' ')?;
+    let (size, file_name) = rest.split_once(' ')?;
+    if CrcRepresentation::Decimal.decode(crc, 4).is_none() || !is_decimal(size) || file_name.is_empty() {
+        return None;
+    }
+    Some(ChecksumEntry {
+        algorithm_name: Some(String::from("cksum")),
+        digest: crc.to_string(),
+        file_name: file_name.to_string(),
+        binary: true,
+    })
+}
+
+/// Checksum files contain hex digests, or padded base64 ones when written by `sha256sum --base64` and the like.  CRCs
+/// may also be written in decimal.
+fn is_checksum_digest(digest: &str) -> bool {
+    let hex_or_base64 = hex::decode(digest).is_ok() || BASE64.decode(digest.as_bytes()).is_ok();
+    !digest.is_empty() && (hex_or_base64 || is_decimal(digest))
+}
+
+fn unescape_file_name(file_name: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(file_name.len());
+    let mut chars = file_name.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                '\\' => unescaped.push('\\'),
+                'n' => unescaped.push('\n'),
+                'r' => unescaped.push('\r'),
+                _ => return None,
+            },
+            _ => unescaped.push(c),
+        }
+    }
+    Some(unescaped)
+}
+
+/// Parses every line of a checksum file, or a JSON manifest written by `format_json_manifest`.  Blank lines and lines
+/// starting with `#` are skipped.  Any other line that can't be parsed, including one that isn't valid UTF-8, is
+/// skipped too, but its line number is recorded so that the check can fail once the rest have been verified, the way
+/// `sha256sum --check` does.  A JSON manifest is a single document, so it's an error if any of it can't be parsed.
+pub fn parse_checksums(read: &mut dyn BufRead) -> Result<ChecksumFile, Error> {
+    let mut content = Vec::new();
+    read.read_to_end(&mut content)?;
+    if content.trim_ascii_start().starts_with(b"{") {
+        let json = std::str::from_utf8(&content).map_err(|_| Error::MalformedChecksumLine { line_number: 1 })?;
+        return Ok(ChecksumFile {
+            entries: parse_json_manifest(json)?,
+            ..ChecksumFile::default()
+        });
+    }
+    let mut checksums = ChecksumFile::default();
+    for (index, line) in content.split(|&b| b == b'\n').enumerate() {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.trim_ascii().is_empty() || line.starts_with(b"#") {
+            continue;
+        }
+        match std::str::from_utf8(line).ok().and_then(parse_line) {
+            Some(entry) => checksums.entries.push(entry),
+            None => checksums.malformed_lines.push(index + 1),
+        }
+    }
+    Ok(checksums)
+}
+
+/// Formats a line the way GNU coreutils does, escaping the file name if it contains a backslash or newline.
+pub fn format_line(digest: &str, file_name: &str, binary: bool) -> String {
+    let mode = match binary {
+        true => '*',
+        false => ' ',
+    };
+    match file_name.contains(['\\', '\n', '\r']) {
+        true => format!("\\{} {}{}", digest, mode, escape_file_name(file_name)),
+        false => format!("{} {}{}", digest, mode, file_name),
+    }
+}
+
+fn escape_file_name(file_name: &str) -> String {
+    file_name
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+/// The token used to identify an algorithm in BSD/`--tag` style lines, e.g. `SHA256` for `SHA-256`.
+pub fn to_tag_name(algorithm_name: &str) -> String {
+    algorithm_name.replace('-', "")
+}
+
+/// Formats a BSD/`--tag` style line, e.g. `SHA256 (file.txt) = <hex>`.
+pub fn format_tag_line(algorithm_name: &str, file_name: &str, digest: &str) -> String {
+    format!("{} ({}) = {}", to_tag_name(algorithm_name), file_name, digest)
+}
+
+/// Formats a JSON manifest listing each file along with its size and a digest for each algorithm.
+pub fn format_json_manifest(files: &[(String, Vec<CalculatedDigest>)]) -> String {
+    let files: Vec<serde_json::Value> = files
+        .iter()
+        .map(|(file_name, calculated)| {
+            let digests: serde_json::Map<String, serde_json::Value> = calculated
+                .iter()
+                .map(|c| (c.algorithm_name.clone(), c.to_hex().into()))
+                .collect();
+            serde_json::json!({
+                "name": file_name,
+                "size": calculated.first().map(|c| c.bytes_read).unwrap_or_default(),
+                "digests": digests,
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&serde_json::json!({ "files": files })).expect("JSON values always serialize")
+}
+
+/// Parses a JSON manifest written by `format_json_manifest` into an entry for each digest of each file.  A manifest
+/// that isn't valid JSON is malformed at the line of the syntax error, and one that isn't shaped like a manifest is
+/// malformed at its first line.
+pub fn parse_json_manifest(json: &str) -> Result<Vec<ChecksumEntry>, Error> {
+    let malformed = |line_number| Error::MalformedChecksumLine { line_number };
+    let manifest: serde_json::Value = serde_json::from_str(json).map_err(|e| malformed(e.line()))?;
+    let mut entries = Vec::new();
+    for file in manifest["files"].as_array().ok_or(malformed(1))? {
+        let file_name = file["name"].as_str().ok_or(malformed(1))?;
+        for (algorithm_name, digest) in file["digests"].as_object().ok_or(malformed(1))? {
+            entries.push(ChecksumEntry {
+                algorithm_name: Some(algorithm_name.clone()),
+                digest: digest.as_str().ok_or(malformed(1))?.to_string(),
+                file_name: file_name.to_string(),
+                binary: true,
+            });
+        }
+    }
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        format_json_manifest, format_line, format_tag_line, parse_checksums, parse_cksum_line, parse_json_manifest,
+        parse_line, parse_tag_line, ChecksumEntry,
+    };
+    use crate::{CalculatedDigest, Error};
+
+    fn entry(digest: &str, file_name: &str, binary: bool) -> ChecksumEntry {
+        ChecksumEntry {
+            algorithm_name: None,
+            digest: digest.to_string(),
+            file_name: file_name.to_string(),
+            binary,
+        }
+    }
+
+    #[test]
+    fn text_mode_line() {
+        assert_eq!(
+            parse_line("d41d8cd98f00b204e9800998ecf8427e  empty.txt"),
+            Some(entry("d41d8cd98f00b204e9800998ecf8427e", "empty.txt", false))
+        );
+    }
+
+    #[test]
+    fn binary_mode_line() {
+        assert_eq!(
+            parse_line("d41d8cd98f00b204e9800998ecf8427e *dir/empty file.bin"),
+            Some(entry("d41d8cd98f00b204e9800998ecf8427e", "dir/empty file.bin", true))
+        );
+    }
+
+    #[test]
+    fn escaped_file_name() {
+        assert_eq!(
+            parse_line("\\d41d8cd98f00b204e9800998ecf8427e  a\\\\b\\nc"),
+            Some(entry("d41d8cd98f00b204e9800998ecf8427e", "a\\b\nc", false))
+        );
+    }
+
+    fn tag_entry(algorithm_name: &str, digest: &str, file_name: &str) -> ChecksumEntry {
+        ChecksumEntry {
+            algorithm_name: Some(algorithm_name.to_string()),
+            ..entry(digest, file_name, true)
+        }
+    }
+
+    #[test]
+    fn bsd_tag_line() {
+        assert_eq!(
+            parse_line("MD5 (dir/a (1).txt) = d41d8cd98f00b204e9800998ecf8427e"),
+            Some(tag_entry("MD5", "d41d8cd98f00b204e9800998ecf8427e", "dir/a (1).txt"))
+        );
+    }
+
+    #[test]
+    fn openssl_tag_line() {
+        assert_eq!(
+            parse_tag_line("SHA1(a.txt)= da39a3ee5e6b4b0d3255bfef95601890afd80709"),
+            Some(tag_entry("SHA1", "da39a3ee5e6b4b0d3255bfef95601890afd80709", "a.txt"))
+        );
+    }
+
+    #[test]
+    fn b2sum_tag_line() {
+        let digest = "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419\
+            d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce";
+        assert_eq!(
+            parse_line(&format!("BLAKE2b (empty.txt) = {}", digest)),
+            Some(tag_entry("BLAKE2b", digest, "empty.txt"))
+        );
+    }
+
+    #[test]
+    fn base64_lines() {
+        assert_eq!(
+            parse_line("1B2M2Y8AsgTpgAmY7PhCfg==  empty.txt"),
+            Some(entry("1B2M2Y8AsgTpgAmY7PhCfg==", "empty.txt", false))
+        );
+        assert_eq!(
+            parse_line("MD5 (empty.txt) = 1B2M2Y8AsgTpgAmY7PhCfg=="),
+            Some(tag_entry("MD5", "1B2M2Y8AsgTpgAmY7PhCfg==", "empty.txt"))
+        );
+    }
+
+    #[test]
+    fn cksum_line() {
+        assert_eq!(
+            parse_line("930766865 9 dir/check file.txt"),
+            Some(tag_entry("cksum", "930766865", "dir/check file.txt"))
+        );
+        assert_eq!(parse_line("4294967295 0 empty"), Some(tag_entry("cksum", "4294967295", "empty")));
+        assert_eq!(parse_cksum_line("4294967296 0 too-big"), None);
+        assert_eq!(parse_cksum_line("930766865 9"), None);
+        assert_eq!(parse_cksum_line("930766865 nine check.txt"), None);
+        assert_eq!(
+            parse_line("12345678  not-cksum.txt"),
+            Some(entry("12345678", "not-cksum.txt", false))
+        );
+        assert_eq!(
+            parse_line("CRC-32 (check.txt) = 3421780262"),
+            Some(tag_entry("CRC-32", "3421780262", "check.txt"))
+        );
+        assert_eq!(parse_line("930766865 *check.txt"), Some(entry("930766865", "check.txt", true)));
+    }
+
+    #[test]
+    fn xxhsum_lines() {
+        assert_eq!(
+            parse_line("XXH3_2d06800538d394c2  empty"),
+            Some(ChecksumEntry {
+                algorithm_name: Some(String::from("XXH3")),
+                ..entry("2d06800538d394c2", "empty", false)
+            })
+        );
+        assert_eq!(parse_line("ef46db3751d8e999  empty"), Some(entry("ef46db3751d8e999", "empty", false)));
+        assert_eq!(
+            parse_line("XXH128 (empty) = 99aa06d3014798d86001c324468d497f"),
+            Some(tag_entry("XXH128", "99aa06d3014798d86001c324468d497f", "empty"))
+        );
+        assert_eq!(parse_line("XXH3_not-hex  empty"), None);
+    }
+
+    #[test]
+    fn invalid_tag_lines() {
+        assert_eq!(parse_tag_line("MD5 (a.txt) d41d8cd98f00b204e9800998ecf8427e"), None);
+        assert_eq!(parse_tag_line("MD5 () = d41d8cd98f00b204e9800998ecf8427e"), None);
+        assert_eq!(parse_tag_line("MD 5 (a.txt) = d41d8cd98f00b204e9800998ecf8427e"), None);
+        assert_eq!(parse_tag_line("MD5 (a.txt) = not-hex"), None);
+    }
+
+    #[test]
+    fn invalid_lines() {
+        assert_eq!(parse_line("d41d8cd98f00b204e9800998ecf8427e"), None);
+        assert_eq!(parse_line("d41d8cd98f00b204e9800998ecf8427e  "), None);
+        assert_eq!(parse_line("not-hex  file"), None);
+        assert_eq!(parse_line("abc  odd-length"), None);
+    }
+
+    #[test]
+    fn checksum_file() {
+        let content = "# comment\r\n\
+            e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  a.txt\r\n\
+            \n\
+            D41D8CD98F00B204E9800998ECF8427E *b.bin\n\
+            SHA1 (c.txt) = da39a3ee5e6b4b0d3255bfef95601890afd80709\n";
+        let checksums = parse_checksums(&mut content.as_bytes()).unwrap();
+        assert!(checksums.malformed_lines.is_empty());
+        assert_eq!(
+            checksums.entries,
+            vec![
+                entry(
+                    "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+                    "a.txt",
+                    false
+                ),
+                entry("D41D8CD98F00B204E9800998ECF8427E", "b.bin", true),
+                tag_entry("SHA1", "da39a3ee5e6b4b0d3255bfef95601890afd80709", "c.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn checksum_file_with_bad_lines() {
+        let content = "garbage\n\
+            e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  a.txt\n\
+            \n\
+            ???\n\
+            SHA1 (c.txt) = da39a3ee5e6b4b0d3255bfef95601890afd80709\n";
+        let checksums = parse_checksums(&mut content.as_bytes()).unwrap();
+        assert_eq!(checksums.malformed_lines, vec![1, 4]);
+        assert_eq!(
+            checksums.entries,
+            vec![
+                entry(
+                    "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+                    "a.txt",
+                    false
+                ),
+                tag_entry("SHA1", "da39a3ee5e6b4b0d3255bfef95601890afd80709", "c.txt"),
+            ]
+        );
+        let content = b"\xffa.txt\n\
+            e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  b\xff.txt\n\
+            d41d8cd98f00b204e9800998ecf8427e  c.txt\n";
+        let checksums = parse_checksums(&mut &content[..]).unwrap();
+        assert_eq!(checksums.malformed_lines, vec![1, 2]);
+        assert_eq!(checksums.entries, vec![entry("d41d8cd98f00b204e9800998ecf8427e", "c.txt", false)]);
+        let err = Error::MalformedChecksumLine { line_number: 4 };
+        assert_eq!(err.to_string(), "Line 4 is not a properly formatted checksum line");
+    }
+
+    #[test]
+    fn formatted_lines_parse_back() {
+        for (file_name, binary) in [("a.txt", false), ("dir/b c.bin", true), ("back\\slash\nnewline", false)] {
+            let line = format_line("d41d8cd98f00b204e9800998ecf8427e", file_name, binary);
+            assert_eq!(
+                parse_line(&line),
+                Some(entry("d41d8cd98f00b204e9800998ecf8427e", file_name, binary))
+            );
+        }
+    }
+
+    #[test]
+    fn formatted_tag_line_parses_back() {
+        let line = format_tag_line("SHA-1", "a.txt", "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(line, "SHA1 (a.txt) = da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(
+            parse_line(&line),
+            Some(tag_entry("SHA1", "da39a3ee5e6b4b0d3255bfef95601890afd80709", "a.txt"))
+        );
+    }
+
+    fn empty_file_digests() -> Vec<CalculatedDigest> {
+        vec![
+            CalculatedDigest {
+                bytes_read: 0,
+                algorithm_name: String::from("MD5"),
+                digest: hex::decode("d41d8cd98f00b204e9800998ecf8427e").unwrap(),
+            },
+            CalculatedDigest {
+                bytes_read: 0,
+                algorithm_name: String::from("SHA-1"),
+                digest: hex::decode("da39a3ee5e6b4b0d3255bfef95601890afd80709").unwrap(),
+            },
+        ]
+    }
+
+    #[test]
+    fn json_manifest() {
+        let calculated = empty_file_digests()[..1].to_vec();
+        let json: serde_json::Value =
+            serde_json::from_str(&format_json_manifest(&[(String::from("empty.txt"), calculated)])).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "files": [{
+                    "name": "empty.txt",
+                    "size": 0,
+                    "digests": { "MD5": "d41d8cd98f00b204e9800998ecf8427e" },
+                }]
+            })
+        );
+    }
+    #[test]
+    fn json_manifest_parses_back() {
+        let files = [
+            (String::from("empty.txt"), empty_file_digests()),
+            (String::from("dir/b c.bin"), empty_file_digests()),
+        ];
+        let manifest = format_json_manifest(&files);
+        let expected: Vec<ChecksumEntry> = files
+            .iter()
+            .flat_map(|(file_name, calculated)| {
+                calculated
+                    .iter()
+                    .map(|c| tag_entry(&c.algorithm_name, &c.to_hex(), file_name))
+            })
+            .collect();
+        assert_eq!(parse_checksums(&mut manifest.as_bytes()).unwrap().entries, expected);
+    }
+
+    #[test]
+    fn malformed_json_manifest() {
+        for (manifest, expected_line_number) in [
+            ("{\n  \"files\": [\n    { \"name\": \"a.txt\",\n  ]\n}", 4),
+            (r#"{ "files": {} }"#, 1),
+            (r#"{ "files": [{ "digests": {} }] }"#, 1),
+            (r#"{ "files": [{ "name": "a.txt", "digests": { "MD5": 1 } }] }"#, 1),
+        ] {
+            match parse_json_manifest(manifest) {
+                Err(Error::MalformedChecksumLine { line_number }) => {
+                    assert_eq!(line_number, expected_line_number, "{}", manifest)
+                }
+                _ => panic!("Expected '{}' to be malformed", manifest),
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+/// Tiger with its original padding, not the Tiger2 variant.
+pub struct Tiger {}
+
+impl Tiger {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 192,
+            name: "Tiger",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Legacy,
+            hasher_fn: new_hasher::<tiger::Tiger>,
+            keying: hmac::<tiger::Tiger>("HMAC-Tiger"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Tiger;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Tiger, empty, U8_EMPTY, "3293ac630c13f0245f92bbb1766e16167a4e58492dde73f3");
+    test_algorithm!(Tiger, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "739414bd4cd6ab967cd46a1d943412757d858b24d1c4ecf7");
+    test_algorithm!(Tiger, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "39e5dc8faa9afd781c7b0edb2a958d27b0961118ddf71e38");
+    test_algorithm!(Tiger, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "486ddd22a8ae20b9fa10ba43cc0e0f185fd8ba287142c919");
+    test_algorithm!(Tiger, one_byte, U8_1_BYTE, "e48e843a543d92bc359df7241f40867e603c9bb4208f995e");
+    test_algorithm!(Tiger, two_bytes, U8_2_BYTES, "a447f2b08a4e452da340999ea67cde802fd667c63dc70e1d");
+    test_algorithm!(Tiger, three_bytes, U8_3_BYTES, "0d9674120c3fcbae1c2c3f8678da733077e25ab914ab63d2");
+    test_algorithm!(Tiger, four_bytes, U8_4_BYTES, "bc4d85151e492ce4dbea45c1eed39b2a4a6608f764d88a26");
+    test_algorithm!(Tiger, ascending, U8_32_ASCENDING, "cf4dd153ea35e0c643f5d6d590174abe3ef1dd1b155276d0");
+    test_algorithm!(Tiger, descending, U8_32_DESCENDING, "2e712f42bb7ef784daeeab9626d62cd5edefb0246e921cbe");
+    test_algorithm_s!(Tiger, hi, S_HI, "a0d4e03bd65678b4a8a5ee917ad36efe6febb9afca8afebd");
+    test_algorithm_s!(Tiger, quick_brown_fox, S_QUICK_BROWN_FOX, "04c3c92d9dc5a6602d1fe3a05c0fda232907fb4ecdd46a18");
+    test_algorithm_s!(Tiger, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "e42e9e7151b7aafdf9a976568443fb291c02a9e7cc747ddf");
+    test_algorithm_s!(Tiger, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "27c318e08a6c31aa5adae3399f1ce79ac398b390ba3e7c0d");
+    test_algorithm_s!(Tiger, abc, "abc", "2aab1484e8c158f2bfb8c5ff41b57a525129131c957b5f93");
+    test_keyed_algorithm_s!(Tiger, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "096c283c443ee8be5f873dc7bfd3700d2e003ddc4f976503");
+}
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::args::SymlinkPolicy;
+use digestify::Error;
+use glob::Pattern;
+use std::path::{Component, Path, PathBuf};
+
+/// Which of the files under the paths given on the command line are verified against a checksum file.
+pub struct WalkOptions {
+    pub recursive: bool,
+    pub include: Vec<Pattern>,
+    pub exclude: Vec<Pattern>,
+    pub hidden: bool,
+    pub symlinks: SymlinkPolicy,
+}
+
+impl WalkOptions {
+    /// Whether a file passes the include and exclude patterns, which are matched against its whole path.
+    pub fn selects(&self, path: &Path) -> bool {
+        (self.include.is_empty() || self.include.iter().any(|p| p.matches_path(path)))
+            && !self.exclude.iter().any(|p| p.matches_path(path))
+    }
+
+    /// Whether a file listed in a checksum file is at or under `roots`, so that it's verified and reported as missing if
+    /// it doesn't exist.  Listed files are checked even if they're hidden, since they were asked for by name.
+    pub fn covers(&self, roots: &[PathBuf], path: &Path) -> bool {
+        roots.iter().any(|root| match path.strip_prefix(root) {
+            Ok(relative) if relative.as_os_str().is_empty() => true,
+            Ok(_) => self.recursive,
+            Err(_) => false,
+        }) && self.selects(path)
+    }
+}
+
+fn is_hidden(name: &std::ffi::OsStr) -> bool {
+    name.to_string_lossy().starts_with('.')
+}
+
+/// Drops any `.` components so that `./dir/file` and `dir/file` compare equal.
+pub fn normalize(path: &Path) -> PathBuf {
+    path.components().filter(|c| *c != Component::CurDir).collect()
+}
+
+/// Returns the files at or under `paths`, in sorted order within each directory.  Paths given explicitly are always
+/// used, while hidden files and symbolic links found while walking directories are subject to `options`.
+pub fn walk(paths: &[String], options: &WalkOptions) -> Result<Vec<PathBuf>, Error> {
+    let mut files = Vec::new();
+    let mut visited = Vec::new();
+    for path in paths {
+        let path = Path::new(path);
+        if path.is_dir() && !options.recursive {
+            return Err(Error::InvalidArgument(format!(
+                "'{}' is a directory.  Use --recursive to verify the files in it.",
+                path.display()
+            )));
+        }
+        walk_path(path, true, options, &mut visited, &mut files)?;
+    }
+    Ok(files)
+}
+
+fn walk_path(
+    path: &Path,
+    is_root: bool,
+    options: &WalkOptions,
+    visited: &mut Vec<PathBuf>,
+    files: &mut Vec<PathBuf>,
+) -> Result<(), Error> {
+    let symlink = path.symlink_metadata()?.file_type().is_symlink();
+    if symlink && !is_root && matches!(options.symlinks, SymlinkPolicy::Skip) {
+        return Ok(());
+    }
+    let metadata = match std::fs::metadata(path) {
+        Ok(metadata) => metadata,
+        // A dangling symbolic link has nothing to verify.
+        Err(_) if symlink => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+    if metadata.is_file() {
+        let path = normalize(path);
+        if options.selects(&path) {
+            files.push(path);
+        }
+        return Ok(());
+    }
+    if !metadata.is_dir() || (symlink && !is_root && !matches!(options.symlinks, SymlinkPolicy::Follow)) {
+        return Ok(());
+    }
+    // Guards against symbolic links that loop back to a directory already walked.
+    let canonical = path.canonicalize()?;
+    if visited.contains(&canonical) {
+        return Ok(());
+    }
+    visited.push(canonical);
+
+    let mut entries = std::fs::read_dir(path)?.collect::<Result<Vec<_>, std::io::Error>>()?;
+    entries.sort_by_key(|e| e.file_name());
+    for entry in entries {
+        if options.hidden || !is_hidden(&entry.file_name()) {
+            walk_path(&entry.path(), false, options, visited, files)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize, WalkOptions};
+    use crate::args::SymlinkPolicy;
+    use glob::Pattern;
+    use std::path::{Path, PathBuf};
+
+    fn options(include: &[&str], exclude: &[&str]) -> WalkOptions {
+        WalkOptions {
+            recursive: true,
+            include: include.iter().map(|p| Pattern::new(p).unwrap()).collect(),
+            exclude: exclude.iter().map(|p| Pattern::new(p).unwrap()).collect(),
+            hidden: false,
+            symlinks: SymlinkPolicy::Files,
+        }
+    }
+
+    #[test]
+    fn include_and_exclude() {
+        let options = options(&["*.tar.gz", "docs/*"], &["*-rc.tar.gz"]);
+        assert!(options.selects(Path::new("dist/app.tar.gz")));
+        assert!(options.selects(Path::new("docs/README")));
+        assert!(!options.selects(Path::new("dist/app-rc.tar.gz")));
+        assert!(!options.selects(Path::new("dist/app.zip")));
+    }
+
+    #[test]
+    fn covers_only_files_under_roots() {
+        let options = options(&[], &[]);
+        let roots = [PathBuf::from("dist"), PathBuf::from("README")];
+        assert!(options.covers(&roots, Path::new("dist/a/app.tar.gz")));
+        assert!(options.covers(&roots, Path::new("README")));
+        assert!(!options.covers(&roots, Path::new("distribution/app.tar.gz")));
+        assert!(options.covers(&roots, Path::new("dist/.cache/app.tar.gz")));
+    }
+
+    #[test]
+    fn current_directory_components_are_ignored() {
+        assert_eq!(normalize(Path::new("./dist/./app.tar.gz")), PathBuf::from("dist/app.tar.gz"));
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::{hmac, new_hasher, Algorithm, Strength};
+
+pub struct Whirlpool {}
+
+impl Whirlpool {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 512,
+            name: "Whirlpool",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::Legacy,
+            hasher_fn: new_hasher::<whirlpool::Whirlpool>,
+            keying: hmac::<whirlpool::Whirlpool>("HMAC-Whirlpool"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Whirlpool;
+    use crate::{test_algorithm, test_algorithm_s, test_keyed_algorithm_s, tests::*};
+    test_algorithm!(Whirlpool, empty, U8_EMPTY, "19fa61d75522a4669b44e39c1d2e1726c530232130d407f89afee0964997f7a73e83be698b288febcf88e3e03c4f0757ea8964e59b63d93708b138cc42a66eb3");
+    test_algorithm!(Whirlpool, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "961b5f299f750f880fca004bdf2882e2fe1b491b0c0ee7e2b514c5dfdd53292dbdbee17e6d3bb5824cdec1867cc7090963be8fff0c1d8ed5864e07cacb50d68a");
+    test_algorithm!(Whirlpool, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "8f3985b30d9bb5384099f71d23bf3d91c0cb0558aaba7f3b35c8f262c290ad1c60a3ac01fa88727979f07c17fc9e5b0f734b6f5984ec7f773d2ae91b7cf179da");
+    test_algorithm!(Whirlpool, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "3fa9409c288db9168376e16c6a065e55f9264bbeeaf1ed082d60ac2b2700980e88defcfef53dbfee41e013213d6c00b5a94ea316c3ba8e65a22fc47a4ffc02d0");
+    test_algorithm!(Whirlpool, one_byte, U8_1_BYTE, "ecb88a0ed2b29417d192fd778d783c0e50ad028fb95188b0cb59ae6b9a8322292aa65e3ae9637c39a59cda503253159083fa79ac55a0b7dd62424e39787d977d");
+    test_algorithm!(Whirlpool, two_bytes, U8_2_BYTES, "c4a4bf8742241fcdd5dbf91d088a3f697dda6b4ebd0a086655d136e846532d16b2110ee15b1d59103c333422d8b4f55e73c1c86c88e0e846871cb253d26cee5e");
+    test_algorithm!(Whirlpool, three_bytes, U8_3_BYTES, "237b9a418aba0844171a415209802fd1f4bcb40687855bdffc6b3bff816d3148b812579ae498751a4a96119b2037891a8ca0288fc74ecce0f50a4621a8a3d245");
+    test_algorithm!(Whirlpool, four_bytes, U8_4_BYTES, "8f9de33a71687fb0c2c75d52cff8c9ff7fc38299c4e10aeadcb506cee85976d717a4a16bdea7c555d0c8ed4de14828e68484a5dabaf83be1389fa6d5ff9b9afd");
+    test_algorithm!(Whirlpool, ascending, U8_32_ASCENDING, "ff72be3b0b2843e5a05c7bdb5607427c3eb8cd35a0ff6b1d94f0b874799474674d38727c6d4a92d5138e048e7abf4501cea50a9c63dd315a4c0964bb897a78a4");
+    test_algorithm!(Whirlpool, descending, U8_32_DESCENDING, "0c9f170b8cb49af0efbb213d092e6c8e62a652ccf407ca7d0ea06b1adbff62ee3b27c6bfbf2174dadbbca34cc8d9fb01d383f1b2a6d8f0537d35387614956cb3");
+    test_algorithm_s!(Whirlpool, hi, S_HI, "bb70a62e264ce0ada5ffeddada80b98a14d388576e627de1ab3a55c3938413d5ebc9987ccf6d41fc021800853fc63bbc4ee07687cfa014d3de2cc8ec14202b27");
+    test_algorithm_s!(Whirlpool, quick_brown_fox, S_QUICK_BROWN_FOX, "cf86d6e4c60b27a3065ddba4bf9c19d7c23cc38deaa9eabebacaada7922c7a472e04c3853685b9a81152d56ba6fd48fee30c093b574d66ba5dbe1319e86b4391");
+    test_algorithm_s!(Whirlpool, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "7a7371f624a859713dcf5a52ac7a3ab5e23cb17b3434999987e75a95f3b73ef965c06bac75621f5425b8fdd1b88cc3e2acbfbe9bbc36491bba94f99c8d23f43b");
+    test_algorithm_s!(Whirlpool, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "ded5cfbd172a16f4416505259318791b7ff15649282f58a8d7d02af34fa140c756ecfb9a7b72e2ba01dcf11dad405497a74a5904af1a615e9a5438d67bdd6207");
+    test_algorithm_s!(Whirlpool, abc, "abc", "4e2448a4c6f486bb16b6562c73b4020bf3043e3a731bce721ae1b303d97e6d4c7181eebdb6c57e277d0e34957114cbd6c797fc9d95d8b582d225292076d4eef5");
+    test_keyed_algorithm_s!(Whirlpool, keyed_quick_brown_fox, KEY_32_BYTES, S_QUICK_BROWN_FOX, "b36d07500af7af68df842a3163129ef0cf1ea10bee620db4cd2e58ab0791ae7bab9074b32c5d1acf1301f8692050749cb96f8ba75e8dd72e3c9756c51525753c");
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U4;
+use xxhash_rust::xxh32;
+
+checksum_digest!(
+    Xxh32Digest,
+    xxh32::Xxh32,
+    U4,
+    xxh32::Xxh32::new(0),
+    xxh32::Xxh32::update,
+    xxh32::Xxh32::digest
+);
+
+/// XXH32, the 32-bit xxHash written by `xxhsum -H0`.
+pub struct Xxh32 {}
+
+impl Xxh32 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 32,
+            name: "XXH32",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Xxh32Digest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Xxh32;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Xxh32, empty, U8_EMPTY, "02cc5d05");
+    test_algorithm!(Xxh32, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "2ca90bd2");
+    test_algorithm!(Xxh32, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "2b61765d");
+    test_algorithm!(Xxh32, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "d03c6d18");
+    test_algorithm!(Xxh32, one_byte, U8_1_BYTE, "f95ad1c7");
+    test_algorithm!(Xxh32, two_bytes, U8_2_BYTES, "28de1bb2");
+    test_algorithm!(Xxh32, three_bytes, U8_3_BYTES, "529d7713");
+    test_algorithm!(Xxh32, four_bytes, U8_4_BYTES, "ff0b5a2f");
+    test_algorithm!(Xxh32, ascending, U8_32_ASCENDING, "7e82d340");
+    test_algorithm!(Xxh32, descending, U8_32_DESCENDING, "55940e2f");
+    test_algorithm_s!(Xxh32, hi, S_HI, "daa7a564");
+    test_algorithm_s!(Xxh32, quick_brown_fox, S_QUICK_BROWN_FOX, "51f8b5f0");
+    test_algorithm_s!(Xxh32, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "ebee2646");
+    test_algorithm_s!(Xxh32, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "a2a1e5af");
+    test_algorithm!(Xxh32, six_thousand_bytes_of_all_ones, U8_6000_ALL_ONES, "c718d90e");
+    test_algorithm_s!(Xxh32, check, "123456789", "937bad67");
+}
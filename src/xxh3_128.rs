@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U16;
+use xxhash_rust::xxh3::Xxh3Default;
+
+checksum_digest!(
+    Xxh3_128Digest,
+    Xxh3Default,
+    U16,
+    Xxh3Default::new(),
+    Xxh3Default::update,
+    Xxh3Default::digest128
+);
+
+/// The 128-bit XXH3, called XXH128 by `xxhsum -H2`.
+pub struct Xxh3_128 {}
+
+impl Xxh3_128 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 128,
+            name: "XXH3-128",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Xxh3_128Digest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Xxh3_128;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Xxh3_128, empty, U8_EMPTY, "99aa06d3014798d86001c324468d497f");
+    test_algorithm!(Xxh3_128, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "9a026d96b3c0f0fca057271c9071c99d");
+    test_algorithm!(Xxh3_128, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "b751c414ad5d69071271460d64cb816b");
+    test_algorithm!(Xxh3_128, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "44256c49b35b96fffe0382022a0ed8a9");
+    test_algorithm!(Xxh3_128, one_byte, U8_1_BYTE, "2d97a8f9e2edaaefe5e72e5e3bec4a78");
+    test_algorithm!(Xxh3_128, two_bytes, U8_2_BYTES, "9296244047c33d80da6e38761eaffcf7");
+    test_algorithm!(Xxh3_128, three_bytes, U8_3_BYTES, "887584c82b42c1978d0dd7c75218735e");
+    test_algorithm!(Xxh3_128, four_bytes, U8_4_BYTES, "aa72a205a50ebfaa130ac729ee3f18e2");
+    test_algorithm!(Xxh3_128, ascending, U8_32_ASCENDING, "6558716845a29a6cd4e2dfc12b4b57ed");
+    test_algorithm!(Xxh3_128, descending, U8_32_DESCENDING, "80c79079604959dad6300f0a857c1300");
+    test_algorithm_s!(Xxh3_128, hi, S_HI, "7d596ce5fcabaf622a2300bbd7ea6e9a");
+    test_algorithm_s!(Xxh3_128, quick_brown_fox, S_QUICK_BROWN_FOX, "c1868a8f7658e4fee76f1dc5f07c5eff");
+    test_algorithm_s!(Xxh3_128, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "5012963173b66e58205a2cdfb8265208");
+    test_algorithm_s!(Xxh3_128, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "c5071739819609d759da8d442064de32");
+    test_algorithm!(Xxh3_128, six_thousand_bytes_of_all_ones, U8_6000_ALL_ONES, "8b4b29cf31b80e7fc66be6f4b5d759b0");
+    test_algorithm_s!(Xxh3_128, check, "123456789", "33119477ede5dcd5e9716427681d5860");
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U8;
+use xxhash_rust::xxh3::Xxh3Default;
+
+checksum_digest!(
+    Xxh3_64Digest,
+    Xxh3Default,
+    U8,
+    Xxh3Default::new(),
+    Xxh3Default::update,
+    Xxh3Default::digest
+);
+
+/// The 64-bit XXH3 written by `xxhsum -H3`, which prefixes it with `XXH3_` to tell it apart from XXH64.
+pub struct Xxh3_64 {}
+
+impl Xxh3_64 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 64,
+            name: "XXH3-64",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Xxh3_64Digest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Xxh3_64;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Xxh3_64, empty, U8_EMPTY, "2d06800538d394c2");
+    test_algorithm!(Xxh3_64, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "a057271c9071c99d");
+    test_algorithm!(Xxh3_64, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "a3bd65cd1d46168c");
+    test_algorithm!(Xxh3_64, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "36f6a79eeaf603f2");
+    test_algorithm!(Xxh3_64, one_byte, U8_1_BYTE, "e5e72e5e3bec4a78");
+    test_algorithm!(Xxh3_64, two_bytes, U8_2_BYTES, "da6e38761eaffcf7");
+    test_algorithm!(Xxh3_64, three_bytes, U8_3_BYTES, "8d0dd7c75218735e");
+    test_algorithm!(Xxh3_64, four_bytes, U8_4_BYTES, "7adfbd7a11ca1156");
+    test_algorithm!(Xxh3_64, ascending, U8_32_ASCENDING, "bfd49bed2d1502eb");
+    test_algorithm!(Xxh3_64, descending, U8_32_DESCENDING, "c436fd4ac7d5ca6b");
+    test_algorithm_s!(Xxh3_64, hi, S_HI, "2a2300bbd7ea6e9a");
+    test_algorithm_s!(Xxh3_64, quick_brown_fox, S_QUICK_BROWN_FOX, "42b8825823f26126");
+    test_algorithm_s!(Xxh3_64, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "a48a542bab73e96e");
+    test_algorithm_s!(Xxh3_64, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "81399267cfa4a0c2");
+    test_algorithm!(Xxh3_64, six_thousand_bytes_of_all_ones, U8_6000_ALL_ONES, "c66be6f4b5d759b0");
+    test_algorithm_s!(Xxh3_64, check, "123456789", "72dcb18b67a17dff");
+}
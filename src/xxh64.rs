@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use crate::checksum_digest::checksum_digest;
+use crate::{new_hasher, Algorithm, Strength};
+use digest::consts::U8;
+use xxhash_rust::xxh64;
+
+checksum_digest!(
+    Xxh64Digest,
+    xxh64::Xxh64,
+    U8,
+    xxh64::Xxh64::new(0),
+    xxh64::Xxh64::update,
+    xxh64::Xxh64::digest
+);
+
+/// XXH64, the 64-bit xxHash written by `xxhsum` by default.
+pub struct Xxh64 {}
+
+impl Xxh64 {
+    pub fn new() -> Algorithm<'static> {
+        Algorithm {
+            digest_bit_size: 64,
+            name: "XXH64",
+            extendable_output: false,
+            crc: false,
+            strength: Strength::NonCryptographic,
+            hasher_fn: new_hasher::<Xxh64Digest>,
+            keying: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Xxh64;
+    use crate::{test_algorithm, test_algorithm_s, tests::*};
+    test_algorithm!(Xxh64, empty, U8_EMPTY, "ef46db3751d8e999");
+    test_algorithm!(Xxh64, thirty_two_bytes_of_all_zeros, U8_32_ALL_ZEROS, "f6e9be5d70632cf5");
+    test_algorithm!(Xxh64, thirty_two_bytes_of_half_ones, U8_32_HALF_ONES, "0fe10de60caa58d7");
+    test_algorithm!(Xxh64, thirty_two_bytes_of_all_ones, U8_32_ALL_ONES, "ff10223616c08787");
+    test_algorithm!(Xxh64, one_byte, U8_1_BYTE, "49eac513f7718934");
+    test_algorithm!(Xxh64, two_bytes, U8_2_BYTES, "fecbcb1c39d82fe9");
+    test_algorithm!(Xxh64, three_bytes, U8_3_BYTES, "43064109e3d5f105");
+    test_algorithm!(Xxh64, four_bytes, U8_4_BYTES, "7725c6ed075f4ef6");
+    test_algorithm!(Xxh64, ascending, U8_32_ASCENDING, "89614b7813c0bd7f");
+    test_algorithm!(Xxh64, descending, U8_32_DESCENDING, "a27eceab1b67f1fc");
+    test_algorithm_s!(Xxh64, hi, S_HI, "ea8842e9ea2638fa");
+    test_algorithm_s!(Xxh64, quick_brown_fox, S_QUICK_BROWN_FOX, "153fd2e76e55f5af");
+    test_algorithm_s!(Xxh64, quick_brown_fox_with_eol, S_QUICK_BROWN_FOX_W_EOL, "4ac18260bc05d788");
+    test_algorithm_s!(Xxh64, qeoic_smouau_poswuc, S_QEOIC_SMOUAU_POSWUC, "bec350e98deaca7f");
+    test_algorithm!(Xxh64, six_thousand_bytes_of_all_ones, U8_6000_ALL_ONES, "582615029f098a93");
+    test_algorithm_s!(Xxh64, check, "123456789", "8cb841db40e6ae83");
+}
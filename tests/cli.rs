@@ -0,0 +1,357 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT-0
+
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+const HI_MD5: &str = "49f68a5c8493ec2c0bf489821c21fc3b";
+const HI_SHA256: &str = "8f434346648f6b96df89dda901c5176b10a6d83961dd3c1ac88b59b2dc327aa4";
+
+/// A scratch directory for one test, removed when it's dropped.
+struct TestDir {
+    path: PathBuf,
+}
+
+impl TestDir {
+    fn new(name: &str) -> TestDir {
+        let path = std::env::temp_dir().join(format!("digestify-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        TestDir { path }
+    }
+
+    fn write(&self, file_name: &str, content: &str) -> &TestDir {
+        let path = self.path.join(file_name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+        self
+    }
+
+    fn run(&self, args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_digestify"))
+            .current_dir(&self.path)
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    /// Runs with `stdin` written to standard input.
+    fn run_with_stdin(&self, args: &[&str], stdin: &str) -> Output {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_digestify"))
+            .current_dir(&self.path)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+        child.wait_with_output().unwrap()
+    }
+
+    fn exit_code(&self, args: &[&str]) -> i32 {
+        self.run(args).status.code().unwrap()
+    }
+}
+
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+#[test]
+fn matching_and_mismatching_digests() {
+    let dir = TestDir::new("matching");
+    dir.write("hi.txt", "hi");
+    assert_eq!(dir.exit_code(&["hi.txt", HI_SHA256]), 0);
+    assert_eq!(dir.exit_code(&["hi.txt", HI_MD5]), 0);
+    assert_eq!(dir.exit_code(&["hi.txt", &HI_SHA256.replace('8', "9")]), 2);
+}
+
+#[test]
+fn usage_errors_exit_1() {
+    let dir = TestDir::new("usage");
+    dir.write("hi.txt", "hi")
+        .write("empty.key", "")
+        .write("SUMS", &format!("{}  hi.txt\n", HI_MD5));
+    assert_eq!(dir.exit_code(&[]), 1);
+    assert_eq!(dir.exit_code(&["--min-strength", "bogus", "hi.txt", HI_MD5]), 1);
+    assert_eq!(dir.exit_code(&["--include", "[", "-c", "SUMS"]), 1);
+    assert_eq!(dir.exit_code(&["generate", "["]), 1);
+    assert_eq!(dir.exit_code(&["--hmac-key-file", "empty.key", "hi.txt", HI_MD5]), 1);
+    assert_eq!(
+        dir.exit_code(&["--hmac-key-env", "DIGESTIFY_TEST_UNSET", "hi.txt", HI_MD5]),
+        1
+    );
+}
+
+#[test]
+fn missing_files_exit_3() {
+    let dir = TestDir::new("missing-files");
+    assert_eq!(dir.exit_code(&["missing.txt", HI_MD5]), 3);
+    assert_eq!(dir.exit_code(&["-c", "MISSING"]), 3);
+    assert_eq!(
+        dir.exit_code(&["--hmac-key-file", "missing.key", "missing.txt", HI_MD5]),
+        3
+    );
+}
+
+#[test]
+fn invalid_digest_exits_5() {
+    let dir = TestDir::new("invalid-digest");
+    dir.write("hi.txt", "hi");
+    let output = dir.run(&["hi.txt", "!!!"]);
+    assert_eq!(output.status.code(), Some(5));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("'!!!' does not seem to be a valid"));
+}
+
+#[test]
+fn unsupported_digest_length_exits_7() {
+    let dir = TestDir::new("unsupported-length");
+    dir.write("hi.txt", "hi");
+    assert_eq!(dir.exit_code(&["hi.txt", "4a"]), 7);
+    assert_eq!(dir.exit_code(&["hi.txt", "abcdef"]), 7);
+    assert_eq!(dir.exit_code(&["-a", "SHAKE128", "hi.txt", "abcdef"]), 7);
+}
+
+#[test]
+fn unknown_algorithm_exits_8() {
+    let dir = TestDir::new("unknown-algorithm");
+    dir.write("hi.txt", "hi")
+        .write("SUMS", &format!("FOO (hi.txt) = {}\n", HI_MD5));
+    assert_eq!(dir.exit_code(&["-a", "foo", "hi.txt", HI_MD5]), 8);
+    assert_eq!(dir.exit_code(&["generate", "-a", "foo", "hi.txt"]), 8);
+    assert_eq!(dir.exit_code(&["-c", "SUMS"]), 8);
+}
+
+#[test]
+fn weak_only_match_exits_9() {
+    let dir = TestDir::new("weak");
+    dir.write("hi.txt", "hi")
+        .write("SUMS", &format!("{}  hi.txt\n", HI_MD5));
+    assert_eq!(dir.exit_code(&["--deny-weak", "hi.txt", HI_MD5]), 9);
+    assert_eq!(dir.exit_code(&["--min-strength", "broken", "hi.txt", HI_MD5]), 0);
+    assert_eq!(dir.exit_code(&["--deny-weak", "hi.txt", HI_SHA256]), 0);
+    assert_eq!(dir.exit_code(&["--deny-weak", "-c", "SUMS"]), 9);
+}
+
+#[test]
+fn generated_manifests_verify() {
+    let dir = TestDir::new("manifests");
+    dir.write("hi.txt", "hi").write("dir/empty.txt", "");
+    let files = ["hi.txt", "dir/empty.txt"];
+    for format in ["gnu", "bsd", "json"] {
+        let manifest = format!("MANIFEST.{}", format);
+        let mut generate = vec![
+            "generate", "-a", "SHA-256", "-a", "MD5", "-a", "CRC-32", "-f", format, "-o", &manifest,
+        ];
+        generate.extend(files);
+        assert_eq!(dir.exit_code(&generate), 0, "{}", format);
+        assert_eq!(dir.exit_code(&["-c", &manifest]), 0, "{}", format);
+    }
+
+    dir.write("hi.txt", "ho");
+    for format in ["gnu", "bsd", "json"] {
+        assert_eq!(dir.exit_code(&["-c", &format!("MANIFEST.{}", format)]), 2, "{}", format);
+    }
+}
+
+#[test]
+fn missing_and_extra_files_are_reported() {
+    let dir = TestDir::new("missing-extra");
+    dir.write("dist/listed.txt", "hi")
+        .write("dist/extra.txt", "hi")
+        .write("dist/.cache/hidden.txt", "hi")
+        .write("dist/.cache/unlisted.txt", "hi")
+        .write(
+            "SUMS",
+            &format!(
+                "{0}  dist/listed.txt\n{0}  dist/.cache/hidden.txt\n{0}  dist/gone.txt\n",
+                HI_SHA256
+            ),
+        );
+    let output = dir.run(&["-c", "SUMS", "-r", "--", "dist"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stdout.contains(" dist/listed.txt: PASS"));
+    assert!(stdout.contains(" dist/.cache/hidden.txt: PASS"));
+    assert!(stdout.contains(" dist/gone.txt: MISSING"));
+    assert!(stdout.contains(" dist/extra.txt: EXTRA"));
+    assert!(!stdout.contains("unlisted.txt"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("FAIL: 1 listed file(s) are missing."));
+    assert!(stderr.contains("FAIL: 1 file(s) are not listed in 'SUMS'."));
+
+    let stdout = String::from_utf8(dir.run(&["-c", "SUMS", "-r", "--hidden", "--", "dist"]).stdout).unwrap();
+    assert!(stdout.contains(" dist/.cache/unlisted.txt: EXTRA"));
+
+    dir.write("SUMS", &format!("{}  dist/listed.txt\n", HI_SHA256));
+    assert_eq!(dir.exit_code(&["-c", "SUMS", "--", "dist/listed.txt"]), 0);
+    let output = dir.run(&["-c", "SUMS", "--", "dist"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("'dist' is a directory.  Use --recursive"));
+    assert_eq!(dir.exit_code(&["-c", "SUMS", "-r", "--", "dist"]), 2);
+    assert_eq!(
+        dir.exit_code(&["-c", "SUMS", "-r", "--exclude", "*/extra.txt", "--", "dist"]),
+        0
+    );
+}
+
+#[test]
+fn standard_input_is_verified() {
+    let dir = TestDir::new("stdin");
+    assert_eq!(dir.run_with_stdin(&["-", HI_SHA256], "hi").status.code(), Some(0));
+    assert_eq!(dir.run_with_stdin(&["-", HI_SHA256], "ho").status.code(), Some(2));
+    assert_eq!(
+        dir.run_with_stdin(&["-a", "MD5", "-", HI_MD5], "hi").status.code(),
+        Some(0)
+    );
+
+    let output = dir.run_with_stdin(&[HI_SHA256], "hi");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("SHA-256"));
+    assert_eq!(dir.run_with_stdin(&[HI_MD5], "ho").status.code(), Some(2));
+
+    let output = dir.run_with_stdin(&["--format", "json", HI_MD5], "hi");
+    assert!(String::from_utf8_lossy(&output.stdout).contains(r#""file":"-""#));
+}
+
+#[test]
+fn malformed_lines_are_skipped_then_exit_6() {
+    let dir = TestDir::new("malformed");
+    dir.write("hi.txt", "hi")
+        .write("SUMS", &format!("garbage\n{}  hi.txt\nnot-a-checksum\n", HI_SHA256));
+    let output = dir.run(&["-c", "SUMS"]);
+    assert_eq!(output.status.code(), Some(6));
+    assert!(String::from_utf8_lossy(&output.stdout).contains(" hi.txt: PASS"));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("FAIL: 2 line(s) in 'SUMS' are not properly formatted and were skipped."));
+    assert!(stderr.contains("Line 1 is not a properly formatted checksum line"));
+
+    let output = dir.run(&["--format", "json", "-c", "SUMS"]);
+    assert_eq!(output.status.code(), Some(6));
+    assert!(String::from_utf8_lossy(&output.stdout).contains(r#""malformed":2"#));
+
+    let mut content = format!("{}  hi.txt\n", HI_SHA256).into_bytes();
+    content.extend_from_slice(b"0000  caf\xe9.txt\n");
+    std::fs::write(dir.path.join("SUMS"), content).unwrap();
+    let output = dir.run(&["-c", "SUMS"]);
+    assert_eq!(output.status.code(), Some(6));
+    assert!(String::from_utf8_lossy(&output.stdout).contains(" hi.txt: PASS"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Line 2 is not a properly formatted checksum line"));
+
+    dir.write("SUMS", &format!("garbage\n{}  hi.txt\n", HI_MD5.replace('4', "5")));
+    assert_eq!(dir.exit_code(&["-c", "SUMS"]), 6);
+}
+
+#[test]
+fn closed_output_keeps_exit_code() {
+    let dir = TestDir::new("closed-output");
+    dir.write("hi.txt", "hi")
+        .write("PASSING", &format!("{}  hi.txt\n", HI_SHA256).repeat(2000))
+        .write(
+            "FAILING",
+            &format!("{}  hi.txt\n", HI_SHA256.replace('8', "9")).repeat(2000),
+        );
+    for (sums_file_name, expected_code) in [("PASSING", 0), ("FAILING", 2)] {
+        for format in ["text", "json"] {
+            let mut child = Command::new(env!("CARGO_BIN_EXE_digestify"))
+                .current_dir(&dir.path)
+                .args(["-a", "SHA-256", "--format", format, "-c", sums_file_name])
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .unwrap();
+            let mut line = String::new();
+            BufReader::new(child.stdout.take().unwrap())
+                .read_line(&mut line)
+                .unwrap();
+            let output = child.wait_with_output().unwrap();
+            assert_eq!(
+                output.status.code(),
+                Some(expected_code),
+                "{} {}",
+                sums_file_name,
+                format
+            );
+            assert!(!String::from_utf8_lossy(&output.stderr).contains("panicked"));
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn unwritable_output_exits_3() {
+    let dir = TestDir::new("unwritable-output");
+    dir.write("hi.txt", "hi")
+        .write("SUMS", &format!("{}  hi.txt\n", HI_SHA256));
+    for args in [
+        &["-c", "SUMS"][..],
+        &["--format", "json", "-c", "SUMS"],
+        &["hi.txt", HI_SHA256],
+        &["generate", "hi.txt"],
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_digestify"))
+            .current_dir(&dir.path)
+            .args(args)
+            .stdout(std::fs::File::create("/dev/full").unwrap())
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(3), "{:?}", args);
+        assert!(!String::from_utf8_lossy(&output.stderr).contains("panicked"));
+    }
+}
+
+#[test]
+fn keyed_digests_are_generated_and_verified() {
+    let dir = TestDir::new("keyed-generate");
+    dir.write("hi.txt", "hi").write("key", "secret");
+    let output = dir.run(&["generate", "--hmac-key-file", "key", "-f", "bsd", "hi.txt"]);
+    assert_eq!(output.status.code(), Some(0));
+    let manifest = String::from_utf8(output.stdout).unwrap();
+    assert!(manifest.starts_with("HMACSHA256 (hi.txt) = "));
+    dir.write("MANIFEST", &manifest);
+    assert_eq!(dir.exit_code(&["--hmac-key-file", "key", "-c", "MANIFEST"]), 0);
+    dir.write("key", "other");
+    assert_eq!(dir.exit_code(&["--hmac-key-file", "key", "-c", "MANIFEST"]), 2);
+}
+
+#[cfg(unix)]
+#[test]
+fn non_utf8_key_is_read_from_environment() {
+    use std::os::unix::ffi::OsStrExt;
+    let dir = TestDir::new("non-utf8-key");
+    dir.write("hi.txt", "hi");
+    let key = std::ffi::OsStr::from_bytes(b"se\xffcret");
+    let output = Command::new(env!("CARGO_BIN_EXE_digestify"))
+        .current_dir(&dir.path)
+        .args(["generate", "--hmac-key-env", "DIGESTIFY_TEST_KEY", "hi.txt"])
+        .env("DIGESTIFY_TEST_KEY", key)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let tag = "a7ddd072713ab02ddf76b304b32adcd47a416fe1e2fa4cec9149f1c07336932d";
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), format!("{}  hi.txt\n", tag));
+    let output = Command::new(env!("CARGO_BIN_EXE_digestify"))
+        .current_dir(&dir.path)
+        .args(["--hmac-key-env", "DIGESTIFY_TEST_KEY", "-a", "SHA-256", "hi.txt", tag])
+        .env("DIGESTIFY_TEST_KEY", key)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn tag_line_for_other_file_warns() {
+    let dir = TestDir::new("tag-line");
+    dir.write("hi.txt", "hi");
+    let output = dir.run(&["hi.txt", &format!("SHA256 (other.txt) = {}", HI_SHA256)]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("Warning: The provided digest is for 'other.txt', not 'hi.txt'."));
+
+    let output = dir.run(&["hi.txt", &format!("SHA256 (./hi.txt) = {}", HI_SHA256)]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("Warning"));
+}